@@ -0,0 +1,116 @@
+use ark_bn254::{Bn254, Fr};
+use benchmarks::{
+    bench_gkr_log_inputs, bench_interpolation_log_points, bench_vars, gkr_tree_circuit,
+    random_field_elements,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use gkr::{GkrProver, GkrVerifier};
+use ml_kzg::trusted_setup::Tau;
+use polynomials::UnivariatePolynomial;
+
+fn bench_sumcheck(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sumcheck");
+    group.sample_size(10);
+    for num_vars in bench_vars() {
+        let values: Vec<Fr> = random_field_elements(1 << num_vars);
+        let claimed_sum: Fr = values.iter().sum();
+
+        group.bench_with_input(BenchmarkId::new("prove", num_vars), &values, |b, values| {
+            b.iter(|| sumcheck::proof(black_box(values.clone()), claimed_sum));
+        });
+
+        let (claimed_sum, round_polys) = sumcheck::proof(values.clone(), claimed_sum);
+        group.bench_with_input(
+            BenchmarkId::new("verify", num_vars),
+            &values,
+            |b, values| {
+                b.iter(|| {
+                    sumcheck::verify(black_box(values.clone()), claimed_sum, round_polys.clone())
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_ml_kzg(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ml_kzg");
+    group.sample_size(10);
+    for num_vars in bench_vars() {
+        let values: Vec<Fr> = random_field_elements(1 << num_vars);
+        let point: Vec<Fr> = random_field_elements(num_vars);
+        let taus: Vec<Fr> = random_field_elements(num_vars);
+        let lagrange_basis = Tau::<Bn254>::initialise(taus).lagrange_basis;
+
+        group.bench_with_input(
+            BenchmarkId::new("commit", num_vars),
+            &values,
+            |b, values| {
+                b.iter(|| {
+                    ml_kzg::commit::<Fr, Bn254>(black_box(values.clone()), lagrange_basis.clone())
+                });
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("open", num_vars), &values, |b, values| {
+            b.iter(|| ml_kzg::open(black_box(values), black_box(&point)));
+        });
+    }
+    group.finish();
+}
+
+fn bench_gkr(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gkr");
+    group.sample_size(10);
+    for log_inputs in bench_gkr_log_inputs() {
+        let circuit = gkr_tree_circuit::<Fr>(log_inputs);
+        let inputs: Vec<Fr> = random_field_elements(circuit.num_inputs);
+        let witness = circuit.evaluate(&inputs).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("prove", log_inputs),
+            &circuit,
+            |b, circuit| {
+                b.iter(|| GkrProver::new(black_box(circuit)).prove(&witness).unwrap());
+            },
+        );
+
+        let proof = GkrProver::new(&circuit).prove(&witness).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("verify", log_inputs),
+            &circuit,
+            |b, circuit| {
+                b.iter(|| {
+                    GkrVerifier::new(black_box(circuit))
+                        .verify(&inputs, &proof)
+                        .unwrap()
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_interpolation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lagrange_interpolation");
+    group.sample_size(10);
+    for log_points in bench_interpolation_log_points() {
+        let x: Vec<Fr> = (0..1u64 << log_points).map(Fr::from).collect();
+        let y: Vec<Fr> = random_field_elements(x.len());
+
+        group.bench_with_input(BenchmarkId::new("interpolate", x.len()), &y, |b, y| {
+            b.iter(|| {
+                UnivariatePolynomial::interpolate(black_box(x.clone()), black_box(y.clone()))
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_sumcheck,
+    bench_ml_kzg,
+    bench_gkr,
+    bench_interpolation
+);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! Collects the results of a criterion run into a single JSON report for tracking
+//! regressions between commits.
+//!
+//! Usage: `cargo run -p benchmarks --bin bench_report -- [criterion dir] [output file]`
+//! (defaults: `target/criterion` and `bench_report.json`).
+use serde_json::{json, Value};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn read_json(path: &Path) -> io::Result<Value> {
+    let contents = fs::read_to_string(path)?;
+    serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Finds every `new` directory written by criterion, which holds the latest estimates of one
+/// benchmark.
+fn find_results(dir: &Path, results: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().is_some_and(|name| name == "new")
+            && path.join("estimates.json").exists()
+            && path.join("benchmark.json").exists()
+        {
+            results.push(path);
+        } else {
+            find_results(&path, results)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let criterion_dir = PathBuf::from(args.next().unwrap_or_else(|| "target/criterion".into()));
+    let output = PathBuf::from(args.next().unwrap_or_else(|| "bench_report.json".into()));
+
+    let mut result_dirs = vec![];
+    find_results(&criterion_dir, &mut result_dirs)?;
+    result_dirs.sort();
+
+    let mut benchmarks = vec![];
+    for dir in result_dirs {
+        let benchmark = read_json(&dir.join("benchmark.json"))?;
+        let estimates = read_json(&dir.join("estimates.json"))?;
+        benchmarks.push(json!({
+            "id": benchmark["full_id"],
+            "group": benchmark["group_id"],
+            "function": benchmark["function_id"],
+            "parameter": benchmark["value_str"],
+            "mean_ns": estimates["mean"]["point_estimate"],
+            "median_ns": estimates["median"]["point_estimate"],
+            "std_dev_ns": estimates["std_dev"]["point_estimate"],
+        }));
+    }
+
+    let report = json!({ "benchmarks": benchmarks });
+    fs::write(&output, serde_json::to_string_pretty(&report)?)?;
+    println!(
+        "wrote {} benchmark results to {}",
+        report["benchmarks"].as_array().map_or(0, |b| b.len()),
+        output.display()
+    );
+    Ok(())
+}
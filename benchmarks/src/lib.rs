@@ -0,0 +1,75 @@
+use ark_ff::PrimeField;
+use ark_std::test_rng;
+use gkr::{Circuit, Layer, Op};
+use std::env;
+
+/// Numbers of variables benchmarked when `ZK_BENCH_VARS` is not set.
+pub const DEFAULT_VARS: [usize; 4] = [10, 14, 18, 22];
+
+/// Base-2 logarithms of the number of circuit inputs when `ZK_BENCH_GKR_LOG_INPUTS` is not set.
+pub const DEFAULT_GKR_LOG_INPUTS: [usize; 4] = [10, 12, 14, 16];
+
+/// Base-2 logarithms of the number of points interpolated when
+/// `ZK_BENCH_INTERPOLATION_LOG_POINTS` is not set. Lagrange interpolation is quadratic in
+/// the number of points, so it runs at smaller sizes than the multilinear protocols.
+pub const DEFAULT_INTERPOLATION_LOG_POINTS: [usize; 4] = [4, 6, 8, 10];
+
+fn sizes_from_env(name: &str, default: &[usize]) -> Vec<usize> {
+    match env::var(name) {
+        Ok(value) => value
+            .split(',')
+            .map(|size| {
+                size.trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{} must be a comma separated list of sizes", name))
+            })
+            .collect(),
+        Err(_) => default.to_vec(),
+    }
+}
+
+/// Numbers of variables to benchmark, e.g. `ZK_BENCH_VARS=10,12,14`.
+pub fn bench_vars() -> Vec<usize> {
+    sizes_from_env("ZK_BENCH_VARS", &DEFAULT_VARS)
+}
+
+/// Base-2 logarithms of the GKR circuit sizes, e.g. `ZK_BENCH_GKR_LOG_INPUTS=18,20,22`.
+pub fn bench_gkr_log_inputs() -> Vec<usize> {
+    sizes_from_env("ZK_BENCH_GKR_LOG_INPUTS", &DEFAULT_GKR_LOG_INPUTS)
+}
+
+/// Base-2 logarithms of the interpolation sizes, e.g. `ZK_BENCH_INTERPOLATION_LOG_POINTS=4,8`.
+pub fn bench_interpolation_log_points() -> Vec<usize> {
+    sizes_from_env(
+        "ZK_BENCH_INTERPOLATION_LOG_POINTS",
+        &DEFAULT_INTERPOLATION_LOG_POINTS,
+    )
+}
+
+/// Deterministic pseudo-random field elements, so every run benchmarks the same inputs.
+pub fn random_field_elements<F: PrimeField>(len: usize) -> Vec<F> {
+    let mut rng = test_rng();
+    (0..len).map(|_| F::rand(&mut rng)).collect()
+}
+
+/// A binary tree circuit over `2^log_inputs` inputs whose layers alternate between addition
+/// and multiplication gates.
+pub fn gkr_tree_circuit<F: PrimeField>(log_inputs: usize) -> Circuit<F> {
+    assert!(log_inputs > 0, "a tree circuit needs at least two inputs");
+    let op = |depth: usize| {
+        if depth.is_multiple_of(2) {
+            Op::Add
+        } else {
+            Op::Mul
+        }
+    };
+
+    let mut circuit = Circuit::new(1 << log_inputs);
+    for depth in 0..log_inputs {
+        circuit.add_layer(Layer::adjacent(vec![
+            op(depth);
+            1 << (log_inputs - 1 - depth)
+        ]));
+    }
+    circuit
+}
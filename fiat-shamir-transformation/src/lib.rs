@@ -25,9 +25,8 @@ impl<K: Digest + Clone, F: PrimeField> FiatShamir<K, F> {
 
         let result_bytes: Vec<u8> = result.to_vec();
         self.absorb(&result_bytes);
-    
-        let result_field = F::from_le_bytes_mod_order(&result_bytes);
-        result_field
+
+        F::from_le_bytes_mod_order(&result_bytes)
     }
 }
 #[cfg(test)]
@@ -45,7 +44,7 @@ mod tests {
         let  mut  second: FiatShamir<sha3::digest::core_api::CoreWrapper<sha3::Sha3_256Core>, Fq> =
             FiatShamir::new(hash_function);
         let input = b"biliqis";
-        let input1: &[u8; 7] = b"onikoyi";
+        let _input1: &[u8; 7] = b"onikoyi";
         let input2: &[u8;10] = b"onikashoyi";
         first.absorb(input);
         second.absorb(input2);
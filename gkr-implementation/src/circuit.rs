@@ -0,0 +1,380 @@
+use crate::error::GkrError;
+use crate::wiring::WiringPredicate;
+use crate::witness::LayeredWitness;
+use ark_ff::PrimeField;
+
+/// Operation performed by a gate on the values `x` and `y` of its left and right wires. Unary
+/// operations only read the left wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op<F: PrimeField> {
+    /// `x + y`
+    Add,
+    /// `x * y`
+    Mul,
+    /// `x - y`
+    Sub,
+    /// `x + k`
+    AddConst(F),
+    /// `k * x`
+    MulConst(F),
+    /// `x`, carrying a wire over to a layer of a different size.
+    Identity,
+    Custom(CustomGate<F>),
+}
+
+/// A user-defined gate computing `left * x + right * y + product * x * y + constant`.
+///
+/// Every gate is of this form, so each contributes a term of degree at most two in every
+/// variable to the sumcheck polynomial of its layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomGate<F: PrimeField> {
+    pub left: F,
+    pub right: F,
+    pub product: F,
+    pub constant: F,
+}
+
+/// A fan-in two gate reading the wires `left` and `right` of the previous layer. Any wires may
+/// be read, and the same wire may be read by several gates or twice by one gate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate<F: PrimeField> {
+    pub left: usize,
+    pub right: usize,
+    pub op: Op<F>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Layer<F: PrimeField> {
+    pub gates: Vec<Gate<F>>,
+}
+
+/// Description of a layered arithmetic circuit: the gate types and wiring of every layer, but
+/// no wire values. `layers[0]` is the layer reading the `num_inputs` circuit inputs and the
+/// last layer produces the circuit outputs.
+///
+/// Evaluating the circuit on some inputs with [`Circuit::evaluate`] gives the
+/// [`LayeredWitness`] the prover needs, so one circuit can be proven for many inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Circuit<F: PrimeField> {
+    pub num_inputs: usize,
+    pub layers: Vec<Layer<F>>,
+}
+
+impl<F: PrimeField> Circuit<F> {
+    pub fn new(num_inputs: usize) -> Self {
+        Self {
+            num_inputs,
+            layers: vec![],
+        }
+    }
+
+    pub fn add_layer(&mut self, layer: Layer<F>) {
+        self.layers.push(layer);
+    }
+
+    /// Evaluates the circuit on `inputs`, returning the values on every wire.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `inputs` does not have `num_inputs` values or a gate reads a wire
+    /// past the end of the previous layer.
+    pub fn evaluate(&self, inputs: &[F]) -> Result<LayeredWitness<F>, GkrError> {
+        if inputs.len() != self.num_inputs {
+            return Err(GkrError::InputCountMismatch {
+                expected: self.num_inputs,
+                actual: inputs.len(),
+            });
+        }
+
+        self.check_wires()?;
+
+        let mut layers = vec![inputs.to_vec()];
+        for layer in &self.layers {
+            let previous = layers.last().unwrap();
+            let outputs = layer
+                .gates
+                .iter()
+                .map(|gate| gate.op.apply(previous[gate.left], previous[gate.right]))
+                .collect();
+            layers.push(outputs);
+        }
+
+        Ok(LayeredWitness { layers })
+    }
+
+    /// Checks that every gate reads wires of the previous layer, so neither the prover nor the
+    /// verifier indexes past the end of a layer.
+    pub(crate) fn check_wires(&self) -> Result<(), GkrError> {
+        let mut width = self.num_inputs;
+        for (i, layer) in self.layers.iter().enumerate() {
+            if let Some(gate) = layer
+                .gates
+                .iter()
+                .position(|gate| gate.left >= width || gate.right >= width)
+            {
+                return Err(GkrError::WireOutOfRange { layer: i, gate });
+            }
+            width = layer.gates.len();
+        }
+        Ok(())
+    }
+
+    /// Sparse wiring predicate of `layer`, counted from the output layer.
+    pub(crate) fn wiring_predicate(&self, layer: usize) -> WiringPredicate<F> {
+        let gates = &self.layers[self.layers.len() - 1 - layer].gates;
+        WiringPredicate {
+            output_vars: self.output_vars(layer),
+            input_vars: self.input_vars(layer),
+            triples: gates
+                .iter()
+                .enumerate()
+                .map(|(out, gate)| (out, gate.left, gate.right))
+                .collect(),
+            coefficients: gates.iter().map(|gate| gate.op.coefficients()).collect(),
+        }
+    }
+
+    /// Number of variables indexing the gates of `layer`, counted from the output layer.
+    pub(crate) fn output_vars(&self, layer: usize) -> usize {
+        if layer == 0 {
+            let outputs = self.layers.last().map_or(0, |layer| layer.gates.len());
+            outputs.next_power_of_two().trailing_zeros() as usize
+        } else {
+            self.input_vars(layer - 1)
+        }
+    }
+
+    /// Number of variables indexing the wires read by `layer`, counted from the output layer.
+    pub(crate) fn input_vars(&self, layer: usize) -> usize {
+        let width = match self.layers.len().checked_sub(layer + 2) {
+            Some(below) => self.layers[below].gates.len(),
+            None => self.num_inputs,
+        };
+        wire_vars(width)
+    }
+
+    /// `inputs` padded with zeros to the `2^input_vars` values of the input layer, rejecting a
+    /// slice of the wrong length rather than truncating or silently extending it.
+    pub(crate) fn padded_inputs(&self, inputs: &[F]) -> Result<Vec<F>, GkrError> {
+        if inputs.len() != self.num_inputs {
+            return Err(GkrError::InputCountMismatch {
+                expected: self.num_inputs,
+                actual: inputs.len(),
+            });
+        }
+        let mut padded = inputs.to_vec();
+        padded.resize(1 << wire_vars(self.num_inputs), F::zero());
+        Ok(padded)
+    }
+}
+
+/// Number of variables of the multilinear extension of `width` wire values.
+pub(crate) fn wire_vars(width: usize) -> usize {
+    (width.next_power_of_two().trailing_zeros() as usize).max(1)
+}
+
+impl<F: PrimeField> Layer<F> {
+    pub fn new() -> Self {
+        Self { gates: Vec::new() }
+    }
+
+    /// A layer whose gate `i` applies `ops[i]` to the adjacent wires `2i` and `2i + 1`, as in a
+    /// binary tree.
+    pub fn adjacent(ops: Vec<Op<F>>) -> Self {
+        Self {
+            gates: ops
+                .into_iter()
+                .enumerate()
+                .map(|(i, op)| Gate::new(2 * i, 2 * i + 1, op))
+                .collect(),
+        }
+    }
+
+    pub fn add_gate(&mut self, gate: Gate<F>) {
+        self.gates.push(gate);
+    }
+}
+
+impl<F: PrimeField> Gate<F> {
+    pub fn new(left: usize, right: usize, op: Op<F>) -> Self {
+        Self { left, right, op }
+    }
+
+    /// A gate applying the unary `op` to the wire `input`.
+    pub fn unary(input: usize, op: Op<F>) -> Self {
+        Self::new(input, input, op)
+    }
+}
+
+impl<F: PrimeField> Op<F> {
+    /// The operation as a [`CustomGate`].
+    pub fn coefficients(&self) -> CustomGate<F> {
+        let (zero, one) = (F::zero(), F::one());
+        match *self {
+            Op::Add => CustomGate::new(one, one, zero, zero),
+            Op::Mul => CustomGate::new(zero, zero, one, zero),
+            Op::Sub => CustomGate::new(one, -one, zero, zero),
+            Op::AddConst(k) => CustomGate::new(one, zero, zero, k),
+            Op::MulConst(k) => CustomGate::new(k, zero, zero, zero),
+            Op::Identity => CustomGate::new(one, zero, zero, zero),
+            Op::Custom(gate) => gate,
+        }
+    }
+
+    pub fn apply(&self, x: F, y: F) -> F {
+        match *self {
+            Op::Add => x + y,
+            Op::Mul => x * y,
+            Op::Sub => x - y,
+            Op::AddConst(k) => x + k,
+            Op::MulConst(k) => k * x,
+            Op::Identity => x,
+            Op::Custom(gate) => gate.apply(x, y),
+        }
+    }
+}
+
+impl<F: PrimeField> CustomGate<F> {
+    pub fn new(left: F, right: F, product: F, constant: F) -> Self {
+        Self {
+            left,
+            right,
+            product,
+            constant,
+        }
+    }
+
+    pub fn apply(&self, x: F, y: F) -> F {
+        self.left * x + self.right * y + self.product * x * y + self.constant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn values(values: &[u64]) -> Vec<Fq> {
+        values.iter().map(|&v| Fq::from(v)).collect()
+    }
+
+    #[test]
+    fn test_compute_circuits() {
+        let mut circuit = Circuit::new(8);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Mul, Op::Add, Op::Add]));
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Add]));
+        circuit.add_layer(Layer::adjacent(vec![Op::Add]));
+
+        let witness = circuit
+            .evaluate(&values(&[1, 2, 3, 4, 5, 6, 7, 8]))
+            .unwrap();
+        assert_eq!(witness.outputs(), values(&[41]));
+
+        // the single output gate reads wires (b, c) = (0, 1) of the layer below
+        let output_wiring = circuit.wiring_predicate(0);
+        assert_eq!(output_wiring.triples, vec![(0, 0, 1)]);
+        assert_eq!(
+            (output_wiring.output_vars, output_wiring.input_vars),
+            (0, 1)
+        );
+        assert_eq!(
+            circuit.wiring_predicate(1).triples,
+            vec![(0, 0, 1), (1, 2, 3)]
+        );
+        assert_eq!(witness.get_ws(0), values(&[41]));
+        assert_eq!(witness.get_ws(1), values(&[15, 26]));
+    }
+
+    #[test]
+    fn test_wired_circuit() {
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Mul),
+                Gate::new(1, 3, Op::Add),
+                Gate::new(2, 2, Op::Mul),
+            ],
+        });
+        circuit.add_layer(Layer {
+            gates: vec![Gate::new(0, 2, Op::Add), Gate::new(2, 1, Op::Mul)],
+        });
+        circuit.add_layer(Layer::adjacent(vec![Op::Mul]));
+
+        let witness = circuit.evaluate(&values(&[1, 2, 3, 4])).unwrap();
+        assert_eq!(witness.get_ws(0), values(&[594]));
+        assert_eq!(witness.get_ws(1), values(&[11, 54]));
+        // three gates are padded to four wires
+        assert_eq!(witness.get_ws(2), values(&[2, 6, 9, 0]));
+        assert_eq!(witness.get_ws(3), values(&[1, 2, 3, 4]));
+        assert_eq!(
+            circuit.wiring_predicate(1).triples,
+            vec![(0, 0, 2), (1, 2, 1)]
+        );
+        let input_wiring = circuit.wiring_predicate(2);
+        assert_eq!(input_wiring.triples, vec![(0, 0, 1), (1, 1, 3), (2, 2, 2)]);
+        assert_eq!((input_wiring.output_vars, input_wiring.input_vars), (2, 2));
+    }
+
+    #[test]
+    fn test_evaluate_many_inputs() {
+        let mut circuit = Circuit::new(2);
+        circuit.add_layer(Layer::adjacent(vec![Op::Mul]));
+
+        assert_eq!(
+            circuit.evaluate(&values(&[3, 4])).unwrap().outputs(),
+            values(&[12])
+        );
+        assert_eq!(
+            circuit.evaluate(&values(&[5, 6])).unwrap().outputs(),
+            values(&[30])
+        );
+        assert_eq!(
+            circuit.evaluate(&values(&[5])),
+            Err(GkrError::InputCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_evaluate_rejects_dangling_wire() {
+        let mut circuit = Circuit::new(2);
+        circuit.add_layer(Layer {
+            gates: vec![Gate::new(0, 2, Op::Add)],
+        });
+        assert_eq!(
+            circuit.evaluate(&values(&[3, 4])),
+            Err(GkrError::WireOutOfRange { layer: 0, gate: 0 })
+        );
+    }
+
+    #[test]
+    fn test_gate_types() {
+        let custom = CustomGate::new(
+            Fq::from(2u64),
+            Fq::from(3u64),
+            Fq::from(1u64),
+            Fq::from(1u64),
+        );
+        let (x, y) = (Fq::from(5u64), Fq::from(7u64));
+        assert_eq!(Op::Sub.apply(x, y), -Fq::from(2u64));
+        assert_eq!(Op::AddConst(Fq::from(10u64)).apply(x, y), Fq::from(15u64));
+        assert_eq!(Op::MulConst(Fq::from(4u64)).apply(x, y), Fq::from(20u64));
+        assert_eq!(Op::Identity.apply(x, y), x);
+        assert_eq!(Op::Custom(custom).apply(x, y), Fq::from(67u64));
+
+        let ops = [
+            Op::Add,
+            Op::Mul,
+            Op::Sub,
+            Op::AddConst(Fq::from(10u64)),
+            Op::MulConst(Fq::from(4u64)),
+            Op::Identity,
+            Op::Custom(custom),
+        ];
+        for op in ops {
+            assert_eq!(op.coefficients().apply(x, y), op.apply(x, y));
+        }
+    }
+}
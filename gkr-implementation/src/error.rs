@@ -0,0 +1,161 @@
+use std::fmt;
+use sumcheck::error::SumcheckError;
+
+/// Errors returned by the GKR prover and verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GkrError {
+    /// The circuit has no layers to prove.
+    EmptyCircuit,
+    /// The proof does not contain one sumcheck per circuit layer.
+    LayerCountMismatch { expected: usize, actual: usize },
+    /// The claimed sum of the sumcheck of `layer` is not the claim reduced from the layer above.
+    LayerClaimMismatch { layer: usize },
+    /// The round polynomials of the sumcheck of `layer` are inconsistent.
+    Sumcheck { layer: usize, error: SumcheckError },
+    /// The sumcheck of `layer` does not reduce to the claimed evaluations of the layer below.
+    WiringCheckFailed { layer: usize },
+    /// The proof claims a different number of outputs than the circuit has, after padding to
+    /// a power of two.
+    OutputCountMismatch { expected: usize, actual: usize },
+    /// The verifier was given a different number of inputs than the circuit reads.
+    InputCountMismatch { expected: usize, actual: usize },
+    /// Witness layer `layer`, counted from the inputs, has a different number of values than
+    /// the circuit gives it.
+    WitnessWidthMismatch {
+        layer: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// Gate `gate` of circuit layer `layer` reads a wire past the end of the previous layer.
+    WireOutOfRange { layer: usize, gate: usize },
+    /// The claimed evaluations of the input layer do not match the inputs.
+    InputEvaluationMismatch,
+    /// The ML-KZG setup has a different number of variables than the inputs.
+    SetupSizeMismatch { expected: usize, actual: usize },
+    /// An opening of the input commitment does not verify.
+    InputOpeningFailed,
+}
+
+impl fmt::Display for GkrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GkrError::EmptyCircuit => write!(f, "circuit has no layers"),
+            GkrError::LayerCountMismatch { expected, actual } => write!(
+                f,
+                "proof has {} layer sumchecks but the circuit has {} layers",
+                actual, expected
+            ),
+            GkrError::LayerClaimMismatch { layer } => {
+                write!(f, "sumcheck claim of layer {} does not verify", layer)
+            }
+            GkrError::Sumcheck { layer, error } => {
+                write!(f, "sumcheck of layer {} failed: {}", layer, error)
+            }
+            GkrError::WiringCheckFailed { layer } => write!(
+                f,
+                "sumcheck of layer {} does not match the claimed evaluations of the next layer",
+                layer
+            ),
+            GkrError::OutputCountMismatch { expected, actual } => write!(
+                f,
+                "proof has {} outputs but the circuit has {}",
+                actual, expected
+            ),
+            GkrError::InputCountMismatch { expected, actual } => write!(
+                f,
+                "circuit reads {} inputs but {} were given",
+                expected, actual
+            ),
+            GkrError::WitnessWidthMismatch {
+                layer,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "witness layer {} has {} values but the circuit gives it {}",
+                layer, actual, expected
+            ),
+            GkrError::WireOutOfRange { layer, gate } => write!(
+                f,
+                "gate {} of layer {} reads a wire past the end of the previous layer",
+                gate, layer
+            ),
+            GkrError::InputEvaluationMismatch => {
+                write!(f, "claimed input layer evaluations do not match the inputs")
+            }
+            GkrError::SetupSizeMismatch { expected, actual } => write!(
+                f,
+                "setup has {} variables but the inputs need {}",
+                actual, expected
+            ),
+            GkrError::InputOpeningFailed => {
+                write!(f, "opening of the input commitment does not verify")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GkrError {}
+
+/// Errors returned when reading a circuit in the text or binary format of
+/// [`format`](mod@crate::format).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitFormatError {
+    /// The text does not start with an `inputs` line, or the bytes with the format's magic
+    /// number.
+    MissingHeader,
+    /// The bytes are in a version of the binary format this crate cannot read.
+    UnsupportedVersion(u8),
+    /// Line `line` of the text, counted from one, is neither a directive nor a valid gate.
+    InvalidLine { line: usize },
+    /// Line `line` holds a gate before the first `layer` line.
+    GateOutsideLayer { line: usize },
+    /// Line `line` holds a gate reading a wire past the end of the previous layer.
+    WireOutOfRangeOnLine { line: usize },
+    /// Gate `gate` of layer `layer` in the bytes reads a wire past the end of the previous
+    /// layer.
+    WireOutOfRange { layer: usize, gate: usize },
+    /// The bytes hold an unknown gate tag, an overlong integer or an invalid field element.
+    InvalidEncoding,
+    /// The description ends before its `outputs` marker.
+    Truncated,
+    /// Something follows the `outputs` marker.
+    TrailingData,
+    /// The `outputs` marker disagrees with the width of the last layer.
+    OutputCountMismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for CircuitFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitFormatError::MissingHeader => write!(f, "missing circuit header"),
+            CircuitFormatError::UnsupportedVersion(version) => {
+                write!(f, "unsupported circuit format version {}", version)
+            }
+            CircuitFormatError::InvalidLine { line } => write!(f, "invalid line {}", line),
+            CircuitFormatError::GateOutsideLayer { line } => {
+                write!(f, "gate on line {} comes before the first layer", line)
+            }
+            CircuitFormatError::WireOutOfRangeOnLine { line } => write!(
+                f,
+                "gate on line {} reads a wire past the end of the previous layer",
+                line
+            ),
+            CircuitFormatError::WireOutOfRange { layer, gate } => write!(
+                f,
+                "gate {} of layer {} reads a wire past the end of the previous layer",
+                gate, layer
+            ),
+            CircuitFormatError::InvalidEncoding => write!(f, "invalid circuit encoding"),
+            CircuitFormatError::Truncated => write!(f, "circuit ends before its outputs"),
+            CircuitFormatError::TrailingData => write!(f, "data after the circuit outputs"),
+            CircuitFormatError::OutputCountMismatch { expected, actual } => write!(
+                f,
+                "circuit declares {} outputs but its last layer has {} gates",
+                actual, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CircuitFormatError {}
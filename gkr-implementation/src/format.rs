@@ -0,0 +1,506 @@
+//! Text and binary encodings of a [`Circuit`], so circuits can be written and shared outside
+//! Rust.
+//!
+//! The text format lists the number of inputs, then each layer from the inputs up as a `layer`
+//! line followed by one gate per line, and ends with the number of outputs:
+//!
+//! ```text
+//! # x0 * x1 + (x2 - 5)
+//! inputs 3
+//! layer
+//!   mul 0 1
+//!   addconst(-5) 2
+//! layer
+//!   add 0 1
+//! outputs 1
+//! ```
+//!
+//! A gate is its operation followed by the wires it reads in the previous layer: `add`, `mul`
+//! and `sub` read two, `identity`, `addconst(k)` and `mulconst(k)` one, and
+//! `custom(left,right,product,constant)` two, all of which must exist in the previous layer.
+//! Unary gates may list a second wire, which is ignored when evaluating. Constants are
+//! decimal, optionally negative, and `#` starts a comment. [`Circuit`] implements [`FromStr`]
+//! to parse this format and [`Display`] to write it.
+//!
+//! The binary format written by [`Circuit::to_bytes`] holds the same information: the magic
+//! bytes `GKRC` and a version byte, then LEB128 integers for the number of inputs, the number
+//! of layers and the gates of each layer, every gate as a tag byte, its two wires and its
+//! constants in compressed form, and finally the number of outputs.
+
+use crate::circuit::{Circuit, CustomGate, Gate, Layer, Op};
+use crate::error::CircuitFormatError;
+use ark_ff::PrimeField;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+const MAGIC: &[u8; 4] = b"GKRC";
+const VERSION: u8 = 1;
+
+const TAG_ADD: u8 = 0;
+const TAG_MUL: u8 = 1;
+const TAG_SUB: u8 = 2;
+const TAG_ADD_CONST: u8 = 3;
+const TAG_MUL_CONST: u8 = 4;
+const TAG_IDENTITY: u8 = 5;
+const TAG_CUSTOM: u8 = 6;
+
+impl<F: PrimeField> Circuit<F> {
+    /// Number of values the circuit outputs: the width of its last layer, or its inputs if it
+    /// has no layers.
+    pub fn num_outputs(&self) -> usize {
+        self.layers
+            .last()
+            .map_or(self.num_inputs, |layer| layer.gates.len())
+    }
+
+    /// Encodes the circuit in the compact binary format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION);
+        write_varint(&mut bytes, self.num_inputs);
+        write_varint(&mut bytes, self.layers.len());
+        for layer in &self.layers {
+            write_varint(&mut bytes, layer.gates.len());
+            for gate in &layer.gates {
+                let (tag, constants) = match gate.op {
+                    Op::Add => (TAG_ADD, vec![]),
+                    Op::Mul => (TAG_MUL, vec![]),
+                    Op::Sub => (TAG_SUB, vec![]),
+                    Op::AddConst(k) => (TAG_ADD_CONST, vec![k]),
+                    Op::MulConst(k) => (TAG_MUL_CONST, vec![k]),
+                    Op::Identity => (TAG_IDENTITY, vec![]),
+                    Op::Custom(custom) => (
+                        TAG_CUSTOM,
+                        vec![custom.left, custom.right, custom.product, custom.constant],
+                    ),
+                };
+                bytes.push(tag);
+                write_varint(&mut bytes, gate.left);
+                write_varint(&mut bytes, gate.right);
+                for constant in constants {
+                    constant
+                        .serialize_compressed(&mut bytes)
+                        .expect("serializing into a vector cannot fail");
+                }
+            }
+        }
+        write_varint(&mut bytes, self.num_outputs());
+        bytes
+    }
+
+    /// Decodes a circuit written by [`Circuit::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not a complete encoding of a circuit in a supported
+    /// version, if a gate reads a wire past the end of the previous layer, or if their output
+    /// count disagrees with the last layer.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, CircuitFormatError> {
+        let mut reader = bytes
+            .strip_prefix(MAGIC)
+            .ok_or(CircuitFormatError::MissingHeader)?;
+        match read_byte(&mut reader)? {
+            VERSION => {}
+            version => return Err(CircuitFormatError::UnsupportedVersion(version)),
+        }
+
+        let mut circuit = Circuit::new(read_varint(&mut reader)?);
+        let layer_count = read_varint(&mut reader)?;
+        let mut width = circuit.num_inputs;
+        for layer_index in 0..layer_count {
+            let gate_count = read_varint(&mut reader)?;
+            let mut layer = Layer::new();
+            for gate_index in 0..gate_count {
+                let tag = read_byte(&mut reader)?;
+                let (left, right) = (read_varint(&mut reader)?, read_varint(&mut reader)?);
+                let op = match tag {
+                    TAG_ADD => Op::Add,
+                    TAG_MUL => Op::Mul,
+                    TAG_SUB => Op::Sub,
+                    TAG_ADD_CONST => Op::AddConst(read_field(&mut reader)?),
+                    TAG_MUL_CONST => Op::MulConst(read_field(&mut reader)?),
+                    TAG_IDENTITY => Op::Identity,
+                    TAG_CUSTOM => Op::Custom(CustomGate::new(
+                        read_field(&mut reader)?,
+                        read_field(&mut reader)?,
+                        read_field(&mut reader)?,
+                        read_field(&mut reader)?,
+                    )),
+                    _ => return Err(CircuitFormatError::InvalidEncoding),
+                };
+                if left >= width || right >= width {
+                    return Err(CircuitFormatError::WireOutOfRange {
+                        layer: layer_index,
+                        gate: gate_index,
+                    });
+                }
+                layer.add_gate(Gate::new(left, right, op));
+            }
+            width = gate_count;
+            circuit.add_layer(layer);
+        }
+
+        check_outputs(&circuit, read_varint(&mut reader)?)?;
+        if !reader.is_empty() {
+            return Err(CircuitFormatError::TrailingData);
+        }
+        Ok(circuit)
+    }
+}
+
+impl<F: PrimeField> Display for Circuit<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "inputs {}", self.num_inputs)?;
+        for layer in &self.layers {
+            writeln!(f, "layer")?;
+            for gate in &layer.gates {
+                let op = match gate.op {
+                    Op::Add => "add".to_string(),
+                    Op::Mul => "mul".to_string(),
+                    Op::Sub => "sub".to_string(),
+                    Op::AddConst(k) => format!("addconst({})", k),
+                    Op::MulConst(k) => format!("mulconst({})", k),
+                    Op::Identity => "identity".to_string(),
+                    Op::Custom(custom) => format!(
+                        "custom({},{},{},{})",
+                        custom.left, custom.right, custom.product, custom.constant
+                    ),
+                };
+                if is_unary(&gate.op) && gate.left == gate.right {
+                    writeln!(f, "  {} {}", op, gate.left)?;
+                } else {
+                    writeln!(f, "  {} {} {}", op, gate.left, gate.right)?;
+                }
+            }
+        }
+        writeln!(f, "outputs {}", self.num_outputs())
+    }
+}
+
+impl<F: PrimeField> FromStr for Circuit<F> {
+    type Err = CircuitFormatError;
+
+    /// Parses a circuit in the text format described in the [module docs](mod@crate::format).
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split('#').next().unwrap_or("").trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let mut circuit = match lines.next() {
+            Some((line, content)) => match content.strip_prefix("inputs") {
+                Some(count) => Circuit::new(parse_count(count, line)?),
+                None => return Err(CircuitFormatError::MissingHeader),
+            },
+            None => return Err(CircuitFormatError::MissingHeader),
+        };
+
+        for (line, content) in lines.by_ref() {
+            if content == "layer" {
+                circuit.add_layer(Layer::new());
+            } else if let Some(count) = content.strip_prefix("outputs") {
+                check_outputs(&circuit, parse_count(count, line)?)?;
+                return match lines.next() {
+                    Some(_) => Err(CircuitFormatError::TrailingData),
+                    None => Ok(circuit),
+                };
+            } else {
+                let gate = parse_gate(content, line)?;
+                let width = match circuit.layers.len() {
+                    0 => return Err(CircuitFormatError::GateOutsideLayer { line }),
+                    1 => circuit.num_inputs,
+                    layers => circuit.layers[layers - 2].gates.len(),
+                };
+                if gate.left >= width || gate.right >= width {
+                    return Err(CircuitFormatError::WireOutOfRangeOnLine { line });
+                }
+                circuit.layers.last_mut().unwrap().add_gate(gate);
+            }
+        }
+        Err(CircuitFormatError::Truncated)
+    }
+}
+
+fn is_unary<F: PrimeField>(op: &Op<F>) -> bool {
+    matches!(op, Op::AddConst(_) | Op::MulConst(_) | Op::Identity)
+}
+
+fn check_outputs<F: PrimeField>(
+    circuit: &Circuit<F>,
+    outputs: usize,
+) -> Result<(), CircuitFormatError> {
+    if outputs != circuit.num_outputs() {
+        return Err(CircuitFormatError::OutputCountMismatch {
+            expected: circuit.num_outputs(),
+            actual: outputs,
+        });
+    }
+    Ok(())
+}
+
+/// Parses the count following an `inputs` or `outputs` keyword.
+fn parse_count(count: &str, line: usize) -> Result<usize, CircuitFormatError> {
+    // The keyword must be followed by whitespace, so `inputs8` is not a header
+    if !count.starts_with(char::is_whitespace) {
+        return Err(CircuitFormatError::InvalidLine { line });
+    }
+    count
+        .trim()
+        .parse()
+        .map_err(|_| CircuitFormatError::InvalidLine { line })
+}
+
+/// Parses a gate line such as `add 0 1` or `custom(1,2,3,4) 0 1`.
+fn parse_gate<F: PrimeField>(content: &str, line: usize) -> Result<Gate<F>, CircuitFormatError> {
+    let invalid = CircuitFormatError::InvalidLine { line };
+    let (op, wires) = match content.find(')') {
+        Some(end) => content.split_at(end + 1),
+        None => content
+            .split_once(char::is_whitespace)
+            .unwrap_or((content, "")),
+    };
+    let (name, constants) = match op.split_once('(') {
+        Some((name, constants)) => {
+            let constants = constants.strip_suffix(')').ok_or(invalid.clone())?;
+            let constants = constants
+                .split(',')
+                .map(parse_field)
+                .collect::<Option<Vec<F>>>()
+                .ok_or(invalid.clone())?;
+            (name.trim(), constants)
+        }
+        None => (op, vec![]),
+    };
+    let wires = wires
+        .split_whitespace()
+        .map(|wire| wire.parse())
+        .collect::<Result<Vec<usize>, _>>()
+        .map_err(|_| invalid.clone())?;
+
+    let op = match (name, constants.as_slice()) {
+        ("add", []) => Op::Add,
+        ("mul", []) => Op::Mul,
+        ("sub", []) => Op::Sub,
+        ("addconst", &[k]) => Op::AddConst(k),
+        ("mulconst", &[k]) => Op::MulConst(k),
+        ("identity", []) => Op::Identity,
+        ("custom", &[left, right, product, constant]) => {
+            Op::Custom(CustomGate::new(left, right, product, constant))
+        }
+        _ => return Err(invalid),
+    };
+    match (wires.as_slice(), is_unary(&op)) {
+        (&[input], true) => Ok(Gate::unary(input, op)),
+        (&[left, right], _) => Ok(Gate::new(left, right, op)),
+        _ => Err(invalid),
+    }
+}
+
+/// Parses a decimal field element, which may be negative.
+fn parse_field<F: PrimeField>(value: &str) -> Option<F> {
+    let value = value.trim();
+    match value.strip_prefix('-') {
+        Some(magnitude) => parse_magnitude(magnitude).map(|k: F| -k),
+        None => parse_magnitude(value),
+    }
+}
+
+fn parse_magnitude<F: PrimeField>(value: &str) -> Option<F> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    F::from_str(value).ok()
+}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn read_byte(reader: &mut &[u8]) -> Result<u8, CircuitFormatError> {
+    let (&byte, rest) = reader.split_first().ok_or(CircuitFormatError::Truncated)?;
+    *reader = rest;
+    Ok(byte)
+}
+
+fn read_varint(reader: &mut &[u8]) -> Result<usize, CircuitFormatError> {
+    let mut value = 0usize;
+    for shift in (0..usize::BITS).step_by(7) {
+        let byte = read_byte(reader)?;
+        let bits = (byte & 0x7f) as usize;
+        if bits.checked_shl(shift).map(|shifted| shifted >> shift) != Some(bits) {
+            return Err(CircuitFormatError::InvalidEncoding);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(CircuitFormatError::InvalidEncoding)
+}
+
+fn read_field<F: PrimeField>(reader: &mut &[u8]) -> Result<F, CircuitFormatError> {
+    F::deserialize_compressed(&mut *reader).map_err(|error| match error {
+        ark_serialize::SerializationError::IoError(_) => CircuitFormatError::Truncated,
+        _ => CircuitFormatError::InvalidEncoding,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_circuit() -> Circuit<Fq> {
+        let custom = CustomGate::new(
+            Fq::from(2u64),
+            Fq::from(3u64),
+            Fq::from(1u64),
+            -Fq::from(1u64),
+        );
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Mul),
+                Gate::new(2, 3, Op::Sub),
+                Gate::unary(3, Op::AddConst(Fq::from(5u64))),
+                Gate::new(1, 2, Op::Identity),
+            ],
+        });
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Custom(custom)),
+                Gate::unary(2, Op::MulConst(Fq::from(300u64))),
+                Gate::new(3, 3, Op::Add),
+            ],
+        });
+        circuit
+    }
+
+    #[test]
+    fn test_text_round_trip() {
+        let circuit = test_circuit();
+        let text = circuit.to_string();
+        assert!(text.starts_with("inputs 4\nlayer\n  mul 0 1\n"));
+        assert!(text.contains("  addconst(5) 3\n  identity 1 2\n"));
+        assert_eq!(text.parse::<Circuit<Fq>>(), Ok(circuit));
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let circuit = test_circuit();
+        let bytes = circuit.to_bytes();
+        assert_eq!(&bytes[..5], b"GKRC\x01");
+        assert_eq!(Circuit::from_bytes(&bytes), Ok(circuit));
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(CircuitFormatError::Truncated)
+        );
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&[bytes.as_slice(), &[0]].concat()),
+            Err(CircuitFormatError::TrailingData)
+        );
+    }
+
+    #[test]
+    fn test_parse_hand_written_circuit() {
+        let text = "
+            # x0 * x1 + (x2 - 5)
+            inputs 3
+
+            layer
+              mul 0 1
+              addconst(-5) 2   # shifted input
+            layer
+              add 0 1
+            outputs 1
+        ";
+        let circuit: Circuit<Fq> = text.parse().unwrap();
+        let inputs = [3u64, 4, 9].map(Fq::from);
+        assert_eq!(
+            circuit.evaluate(&inputs).unwrap().outputs(),
+            [Fq::from(16u64)]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse = |text: &str| text.parse::<Circuit<Fq>>().unwrap_err();
+        assert_eq!(
+            parse("layer\nadd 0 1\noutputs 1"),
+            CircuitFormatError::MissingHeader
+        );
+        assert_eq!(
+            parse("inputs 2\nadd 0 1\noutputs 1"),
+            CircuitFormatError::GateOutsideLayer { line: 2 }
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\nadd 0\noutputs 1"),
+            CircuitFormatError::InvalidLine { line: 3 }
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\ndiv 0 1\noutputs 1"),
+            CircuitFormatError::InvalidLine { line: 3 }
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\naddconst(x) 0\noutputs 1"),
+            CircuitFormatError::InvalidLine { line: 3 }
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\nadd 0 1\noutputs 2"),
+            CircuitFormatError::OutputCountMismatch {
+                expected: 1,
+                actual: 2
+            }
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\nadd 0 1"),
+            CircuitFormatError::Truncated
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\nadd 0 1\noutputs 1\nlayer"),
+            CircuitFormatError::TrailingData
+        );
+        assert_eq!(
+            parse("inputs 2\nlayer\nadd 0 5\noutputs 1"),
+            CircuitFormatError::WireOutOfRangeOnLine { line: 3 }
+        );
+        assert_eq!(
+            parse("inputs 4\nlayer\nadd 0 1\nlayer\nidentity 1\noutputs 1"),
+            CircuitFormatError::WireOutOfRangeOnLine { line: 5 }
+        );
+    }
+
+    #[test]
+    fn test_binary_errors() {
+        let bytes = test_circuit().to_bytes();
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&bytes[1..]),
+            Err(CircuitFormatError::MissingHeader)
+        );
+        let mut unsupported = bytes.clone();
+        unsupported[4] = 2;
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&unsupported),
+            Err(CircuitFormatError::UnsupportedVersion(2))
+        );
+        // The first gate's tag follows the header, the input, layer and gate counts
+        let mut unknown_op = bytes.clone();
+        unknown_op[8] = 7;
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&unknown_op),
+            Err(CircuitFormatError::InvalidEncoding)
+        );
+        // ...and its left wire follows the tag
+        let mut dangling = bytes.clone();
+        dangling[9] = 9;
+        assert_eq!(
+            Circuit::<Fq>::from_bytes(&dangling),
+            Err(CircuitFormatError::WireOutOfRange { layer: 0, gate: 0 })
+        );
+    }
+}
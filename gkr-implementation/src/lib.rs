@@ -0,0 +1,422 @@
+//! GKR interactive proofs for layered arithmetic circuits, made non-interactive with the
+//! Fiat–Shamir transformation.
+//!
+//! A [`Circuit`] describes the gates and wiring of each layer. Evaluating it on some inputs
+//! gives a [`LayeredWitness`] that a [`GkrProver`] proves, producing a [`GkrProof`] that a
+//! [`GkrVerifier`] checks against the same circuit and the inputs.
+//!
+//! The inputs can also be left out of the proof: [`GkrProver::prove_committed`] commits to them
+//! with ML-KZG and the resulting [`CommittedGkrProof`] is checked against the commitment alone.
+//!
+//! Circuits can be written in the text and binary formats of [`format`](mod@format) and loaded from them.
+
+pub mod circuit;
+pub mod error;
+pub mod format;
+mod libra;
+pub mod proof;
+pub mod prover;
+mod transcript;
+pub mod verifier;
+pub mod wiring;
+pub mod witness;
+
+pub use circuit::{Circuit, CustomGate, Gate, Layer, Op};
+pub use error::{CircuitFormatError, GkrError};
+pub use proof::{CommittedGkrProof, GkrProof};
+pub use prover::GkrProver;
+pub use verifier::GkrVerifier;
+pub use wiring::{WiringPredicate, WiringPredicates};
+pub use witness::LayeredWitness;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Bn254, Fq, Fr};
+    use ml_kzg::trusted_setup::Tau;
+
+    fn test_circuit() -> Circuit<Fq> {
+        let mut circuit = Circuit::new(8);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Mul, Op::Add, Op::Add]));
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Add]));
+        circuit.add_layer(Layer::adjacent(vec![Op::Add]));
+        circuit
+    }
+
+    fn test_inputs() -> Vec<Fq> {
+        (1..=8u64).map(Fq::from).collect()
+    }
+
+    fn test_proof(circuit: &Circuit<Fq>) -> GkrProof<Fq> {
+        let witness = circuit.evaluate(&test_inputs()).unwrap();
+        GkrProver::new(circuit).prove(&witness).unwrap()
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let circuit = test_circuit();
+        let gkr_proof = test_proof(&circuit);
+        assert_eq!(gkr_proof.sumcheck_proof.len(), circuit.layers.len());
+        assert_eq!(gkr_proof.output_mle, vec![Fq::from(41u64)]);
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&test_inputs(), &gkr_proof),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_prove_many_inputs() {
+        let circuit = test_circuit();
+        let verifier = GkrVerifier::new(&circuit);
+        for offset in 0..3u64 {
+            let inputs: Vec<Fq> = (1..=8u64).map(|i| Fq::from(i * i + offset)).collect();
+            let witness = circuit.evaluate(&inputs).unwrap();
+            let gkr_proof = GkrProver::new(&circuit).prove(&witness).unwrap();
+            assert_eq!(verifier.verify(&inputs, &gkr_proof), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_prove_and_verify_wired_circuit() {
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Mul),
+                Gate::new(1, 3, Op::Add),
+                Gate::new(2, 2, Op::Mul),
+            ],
+        });
+        circuit.add_layer(Layer {
+            gates: vec![Gate::new(0, 2, Op::Add), Gate::new(2, 1, Op::Mul)],
+        });
+        circuit.add_layer(Layer::adjacent(vec![Op::Mul]));
+
+        let inputs: Vec<Fq> = (1..=4u64).map(Fq::from).collect();
+        let witness = circuit.evaluate(&inputs).unwrap();
+        let gkr_proof = GkrProver::new(&circuit).prove(&witness).unwrap();
+        assert_eq!(gkr_proof.output_mle[0], Fq::from(594u64));
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&inputs, &gkr_proof),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_prove_and_verify_many_outputs() {
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Mul]));
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Add),
+                Gate::new(1, 1, Op::Mul),
+                Gate::new(0, 0, Op::Mul),
+            ],
+        });
+
+        let inputs: Vec<Fq> = (1..=4u64).map(Fq::from).collect();
+        let witness = circuit.evaluate(&inputs).unwrap();
+        let mut gkr_proof = GkrProver::new(&circuit).prove(&witness).unwrap();
+        assert_eq!(
+            gkr_proof.output_mle,
+            [15u64, 144, 9, 0].map(Fq::from).to_vec()
+        );
+        let verifier = GkrVerifier::new(&circuit);
+        assert_eq!(verifier.verify(&inputs, &gkr_proof), Ok(()));
+
+        gkr_proof.output_mle.pop();
+        assert_eq!(
+            verifier.verify(&inputs, &gkr_proof),
+            Err(GkrError::OutputCountMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_prove_and_verify_gate_types() {
+        let custom = CustomGate::new(
+            Fq::from(2u64),
+            Fq::from(3u64),
+            Fq::from(1u64),
+            Fq::from(1u64),
+        );
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Sub),
+                Gate::unary(2, Op::AddConst(Fq::from(10u64))),
+                Gate::unary(3, Op::MulConst(Fq::from(4u64))),
+                Gate::unary(0, Op::Identity),
+                Gate::new(1, 2, Op::Custom(custom)),
+            ],
+        });
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Mul),
+                Gate::unary(4, Op::Identity),
+                Gate::new(2, 3, Op::Add),
+            ],
+        });
+        circuit.add_layer(Layer {
+            gates: vec![Gate::new(1, 0, Op::Sub), Gate::unary(2, Op::Identity)],
+        });
+
+        let inputs: Vec<Fq> = [3u64, 5, 7, 2].map(Fq::from).to_vec();
+        let witness = circuit.evaluate(&inputs).unwrap();
+        assert_eq!(witness.outputs(), [101u64, 11].map(Fq::from));
+
+        let gkr_proof = GkrProver::new(&circuit).prove(&witness).unwrap();
+        let verifier = GkrVerifier::new(&circuit);
+        assert_eq!(verifier.verify(&inputs, &gkr_proof), Ok(()));
+
+        let mut other_inputs = inputs.clone();
+        other_inputs[3] = Fq::from(9u64);
+        assert!(verifier.verify(&other_inputs, &gkr_proof).is_err());
+    }
+
+    #[test]
+    fn test_rejects_tampered_claims() {
+        let circuit = test_circuit();
+        let mut gkr_proof = test_proof(&circuit);
+        gkr_proof.w_s[0].0 += Fq::from(1u64);
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&test_inputs(), &gkr_proof),
+            Err(GkrError::WiringCheckFailed { layer: 0 })
+        );
+    }
+
+    #[test]
+    fn test_rejects_tampered_round_polys() {
+        let circuit = test_circuit();
+        let mut gkr_proof = test_proof(&circuit);
+        gkr_proof.sumcheck_proof[1].1[2][0] += Fq::from(1u64);
+        assert!(matches!(
+            GkrVerifier::new(&circuit).verify(&test_inputs(), &gkr_proof),
+            Err(GkrError::Sumcheck { layer: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_wrong_output() {
+        let circuit = test_circuit();
+        let mut gkr_proof = test_proof(&circuit);
+        gkr_proof.output_mle[0] = Fq::from(42u64);
+        assert!(GkrVerifier::new(&circuit)
+            .verify(&test_inputs(), &gkr_proof)
+            .is_err());
+    }
+
+    #[test]
+    fn test_rejects_wrong_inputs() {
+        let circuit = test_circuit();
+        let gkr_proof = test_proof(&circuit);
+        let mut inputs = test_inputs();
+        inputs[3] = Fq::from(9u64);
+        // The inputs are absorbed before any challenge, so other inputs change every challenge
+        assert!(GkrVerifier::new(&circuit)
+            .verify(&inputs, &gkr_proof)
+            .is_err());
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&inputs[..4], &gkr_proof),
+            Err(GkrError::InputCountMismatch {
+                expected: 8,
+                actual: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_too_many_inputs() {
+        let circuit = test_circuit();
+        let gkr_proof = test_proof(&circuit);
+        let mut inputs = test_inputs();
+        inputs.push(Fq::from(0u64));
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&inputs, &gkr_proof),
+            Err(GkrError::InputCountMismatch {
+                expected: 8,
+                actual: 9
+            })
+        );
+
+        let mut witness = circuit.evaluate(&test_inputs()).unwrap();
+        witness.layers[0] = inputs;
+        assert_eq!(
+            GkrProver::new(&circuit).prove(&witness),
+            Err(GkrError::InputCountMismatch {
+                expected: 8,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_inputs_chosen_after_the_challenges() {
+        // x0 + x1, for which a forger claims the output 100
+        let mut circuit = Circuit::new(2);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add]));
+        let output = Fq::from(100u64);
+
+        // Without the inputs, the forger can only run the transcript on the circuit and output
+        let mut fiat_shamir = transcript::new_transcript::<Fq>();
+        fiat_shamir.absorb(&circuit.to_bytes());
+        fiat_shamir.absorb(&transcript::to_bytes(&[output]));
+        let half = output / Fq::from(2u64);
+        let quarter = half / Fq::from(2u64);
+        let round_polys = vec![vec![half; 3], vec![quarter; 3]];
+        let mut r = vec![];
+        for round_poly in &round_polys {
+            fiat_shamir.absorb(&transcript::to_bytes(round_poly));
+            r.push(fiat_shamir.squeeze());
+        }
+
+        // Make the wiring check pass at (r_b, r_c), then solve W(r_b), W(r_c) for the inputs
+        let wiring = (Fq::from(1u64) - r[0]) * r[1];
+        let (w_rb, w_rc) = (quarter / wiring, Fq::from(0u64));
+        let eq = |r: Fq| [Fq::from(1u64) - r, r];
+        let (eq_b, eq_c) = (eq(r[0]), eq(r[1]));
+        let det = eq_b[0] * eq_c[1] - eq_b[1] * eq_c[0];
+        let inputs = [
+            (w_rb * eq_c[1] - w_rc * eq_b[1]) / det,
+            (w_rc * eq_b[0] - w_rb * eq_c[0]) / det,
+        ];
+        assert_ne!(inputs[0] + inputs[1], output);
+
+        let forged = GkrProof {
+            output_mle: vec![output],
+            sumcheck_proof: vec![(output, round_polys)],
+            w_s: vec![(w_rb, w_rc)],
+        };
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&inputs, &forged),
+            Err(GkrError::WiringCheckFailed { layer: 0 })
+        );
+    }
+
+    fn test_committed_proof() -> (Circuit<Fr>, Tau<Bn254>, CommittedGkrProof<Bn254>) {
+        let mut circuit = Circuit::new(8);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Mul, Op::Sub, Op::Add]));
+        circuit.add_layer(Layer::adjacent(vec![Op::Mul, Op::Add]));
+        let inputs: Vec<Fr> = (1..=8u64).map(Fr::from).collect();
+        let witness = circuit.evaluate(&inputs).unwrap();
+        let tau = Tau::<Bn254>::initialise([3u64, 5, 7].map(Fr::from).to_vec());
+        let proof = GkrProver::new(&circuit)
+            .prove_committed(&witness, &tau)
+            .unwrap();
+        (circuit, tau, proof)
+    }
+
+    #[test]
+    fn test_prove_and_verify_committed_inputs() {
+        let (circuit, tau, proof) = test_committed_proof();
+        assert_eq!(
+            proof.gkr_proof.output_mle,
+            vec![Fr::from(36u64), Fr::from(14u64)]
+        );
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify_committed(&proof, &tau.g2_tau),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_rejects_tampered_committed_proof() {
+        let (circuit, tau, proof) = test_committed_proof();
+        let verifier = GkrVerifier::new(&circuit);
+
+        let mut tampered = proof.clone();
+        tampered.input_openings.0.swap(0, 1);
+        assert_eq!(
+            verifier.verify_committed(&tampered, &tau.g2_tau),
+            Err(GkrError::InputOpeningFailed)
+        );
+
+        // Another commitment changes every challenge, starting with the point the output is claimed at
+        let mut tampered = proof.clone();
+        tampered.input_commitment = tau.lagrange_basis[0];
+        assert_eq!(
+            verifier.verify_committed(&tampered, &tau.g2_tau),
+            Err(GkrError::LayerClaimMismatch { layer: 0 })
+        );
+
+        assert_eq!(
+            verifier.verify_committed(&proof, &tau.g2_tau[..2]),
+            Err(GkrError::SetupSizeMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_prove_committed_rejects_wrong_setup() {
+        let (circuit, _, _) = test_committed_proof();
+        let witness = circuit.evaluate(&[Fr::from(1u64); 8]).unwrap();
+        let tau = Tau::<Bn254>::initialise(vec![Fr::from(3u64), Fr::from(5u64)]);
+        assert_eq!(
+            GkrProver::new(&circuit)
+                .prove_committed(&witness, &tau)
+                .unwrap_err(),
+            GkrError::SetupSizeMismatch {
+                expected: 3,
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_dangling_wire() {
+        let mut circuit = Circuit::new(2);
+        circuit.add_layer(Layer {
+            gates: vec![Gate::new(0, 5, Op::Add)],
+        });
+        let zero = Fq::from(0u64);
+        let gkr_proof = GkrProof {
+            output_mle: vec![zero],
+            sumcheck_proof: vec![(zero, vec![vec![zero; 3]; 2])],
+            w_s: vec![(zero, zero)],
+        };
+        assert_eq!(
+            GkrVerifier::new(&circuit).verify(&[zero, zero], &gkr_proof),
+            Err(GkrError::WireOutOfRange { layer: 0, gate: 0 })
+        );
+    }
+
+    #[test]
+    fn test_prove_rejects_witness_of_wrong_width() {
+        let circuit = test_circuit();
+        let prover = GkrProver::new(&circuit);
+        let mut witness = circuit.evaluate(&test_inputs()).unwrap();
+        witness.layers[1].pop();
+        assert_eq!(
+            prover.prove(&witness),
+            Err(GkrError::WitnessWidthMismatch {
+                layer: 1,
+                expected: 4,
+                actual: 3
+            })
+        );
+
+        let mut witness = circuit.evaluate(&test_inputs()).unwrap();
+        witness.layers[2].push(Fq::from(0u64));
+        assert_eq!(
+            prover.prove(&witness),
+            Err(GkrError::WitnessWidthMismatch {
+                layer: 2,
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_empty_circuit() {
+        let circuit = Circuit::new(2);
+        let witness = circuit.evaluate(&[Fq::from(1u64), Fq::from(2u64)]).unwrap();
+        assert_eq!(
+            GkrProver::new(&circuit).prove(&witness).unwrap_err(),
+            GkrError::EmptyCircuit
+        );
+    }
+}
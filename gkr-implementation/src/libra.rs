@@ -0,0 +1,173 @@
+//! Linear-time prover for the sumcheck of one layer, following the two phases of Libra.
+//!
+//! The sumcheck polynomial of a layer is `f(b, c) = sum_g I(a_g) * pred_g(b, c) * g(W(b), W(c))`
+//! over its gates `g` reading wires `b_g`, `c_g`, where `I(a)` weighs the claims on the layer.
+//! Summing over `c` first leaves `W(b) * A(b) + C(b)` for tables `A`, `C` filled in one pass over
+//! the gates; once `b` is fixed to `r_b`, `f(r_b, c)` is `W(c) * A(c) + C(c)` for two more such
+//! tables. Neither phase touches the `2^(2k)` hypercube of `(b, c)`.
+
+use crate::circuit::Layer;
+use crate::transcript::{to_bytes, Transcript};
+use ark_ff::PrimeField;
+use multilinear_polynomial::eq_poly::eq_table;
+
+/// Sumcheck of a layer reducing the claim on it to claims on `W(r_b)` and `W(r_c)`.
+pub(crate) struct LayerSumcheck<F: PrimeField> {
+    pub(crate) round_polys: Vec<Vec<F>>,
+    pub(crate) r_b: Vec<F>,
+    pub(crate) r_c: Vec<F>,
+    pub(crate) w_rb: F,
+    pub(crate) w_rc: F,
+}
+
+/// Runs the sumcheck of `layer` with the claims on its gates weighted by `output_weights`,
+/// given the values `w` on the wires it reads, padded to a power of two.
+pub(crate) fn prove_layer<F: PrimeField>(
+    layer: &Layer<F>,
+    output_weights: &[F],
+    w: &[F],
+    transcript: &mut Transcript<F>,
+) -> LayerSumcheck<F> {
+    let mut a_b = vec![F::zero(); w.len()];
+    let mut c_b = vec![F::zero(); w.len()];
+    for (gate, weight) in layer.gates.iter().zip(output_weights) {
+        let coefficients = gate.op.coefficients();
+        let w_c = w[gate.right];
+        a_b[gate.left] += *weight * (coefficients.left + coefficients.product * w_c);
+        c_b[gate.left] += *weight * (coefficients.right * w_c + coefficients.constant);
+    }
+    let (mut round_polys, r_b, w_rb) = prove_phase(w.to_vec(), a_b, c_b, transcript);
+
+    let eq_rb = eq_table(&r_b);
+    let mut a_c = vec![F::zero(); w.len()];
+    let mut c_c = vec![F::zero(); w.len()];
+    for (gate, weight) in layer.gates.iter().zip(output_weights) {
+        let coefficients = gate.op.coefficients();
+        let weight = *weight * eq_rb[gate.left];
+        a_c[gate.right] += weight * (coefficients.right + coefficients.product * w_rb);
+        c_c[gate.right] += weight * (coefficients.left * w_rb + coefficients.constant);
+    }
+    let (phase_two_polys, r_c, w_rc) = prove_phase(w.to_vec(), a_c, c_c, transcript);
+    round_polys.extend(phase_two_polys);
+
+    LayerSumcheck {
+        round_polys,
+        r_b,
+        r_c,
+        w_rb,
+        w_rc,
+    }
+}
+
+/// Sumcheck of `W(x) * A(x) + C(x)` over the variables of the tables, binding the most
+/// significant variable first.
+///
+/// # Returns
+///
+/// The round polynomials as their evaluations at `0, 1, 2`, the challenges, and `W` at the
+/// challenges.
+fn prove_phase<F: PrimeField>(
+    mut w: Vec<F>,
+    mut a: Vec<F>,
+    mut c: Vec<F>,
+    transcript: &mut Transcript<F>,
+) -> (Vec<Vec<F>>, Vec<F>, F) {
+    let mut round_polys = vec![];
+    let mut challenges = vec![];
+    while w.len() > 1 {
+        let half = w.len() / 2;
+        let mut round_poly = vec![F::zero(); 3];
+        for j in 0..half {
+            let (w_0, a_0, c_0) = (w[j], a[j], c[j]);
+            let (w_1, a_1, c_1) = (w[j + half], a[j + half], c[j + half]);
+            let (w_2, a_2, c_2) = (w_1.double() - w_0, a_1.double() - a_0, c_1.double() - c_0);
+            round_poly[0] += w_0 * a_0 + c_0;
+            round_poly[1] += w_1 * a_1 + c_1;
+            round_poly[2] += w_2 * a_2 + c_2;
+        }
+
+        transcript.absorb(&to_bytes(&round_poly));
+        let challenge = transcript.squeeze();
+        for table in [&mut w, &mut a, &mut c] {
+            fold(table, challenge);
+        }
+        round_polys.push(round_poly);
+        challenges.push(challenge);
+    }
+
+    (round_polys, challenges, w[0])
+}
+
+/// Fixes the most significant variable of the table to `value`.
+fn fold<F: PrimeField>(table: &mut Vec<F>, value: F) {
+    let half = table.len() / 2;
+    let (low, high) = table.split_at_mut(half);
+    for (low, high) in low.iter_mut().zip(high.iter()) {
+        *low += value * (*high - *low);
+    }
+    table.truncate(half);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::{Circuit, CustomGate, Gate, Op};
+    use crate::transcript::new_transcript;
+    use ark_bn254::Fq;
+    use multilinear_polynomial::{
+        product_poly::{ProductPolynomial, SumPolynomial},
+        EvaluationFormPolynomial,
+    };
+    use sumcheck::gkr_sumcheck;
+
+    #[test]
+    fn test_matches_dense_sumcheck() {
+        let custom = CustomGate::new(
+            Fq::from(2u64),
+            Fq::from(3u64),
+            Fq::from(1u64),
+            Fq::from(1u64),
+        );
+        let mut circuit = Circuit::new(4);
+        circuit.add_layer(Layer::adjacent(vec![Op::Add, Op::Mul]));
+        circuit.add_layer(Layer {
+            gates: vec![
+                Gate::new(0, 1, Op::Custom(custom)),
+                Gate::new(1, 1, Op::Sub),
+                Gate::unary(0, Op::AddConst(Fq::from(5u64))),
+            ],
+        });
+        let witness = circuit.evaluate(&[3u64, 5, 7, 2].map(Fq::from)).unwrap();
+        let w = witness.get_ws(1);
+        let r_1 = [Fq::from(11u64), Fq::from(13u64)];
+
+        // f(b, c) materialised over the whole (b, c) hypercube
+        let predicates = circuit
+            .wiring_predicate(0)
+            .to_dense()
+            .map(|pred| EvaluationFormPolynomial::new(&pred).fix_variables(&r_1, 0));
+        let w_i = EvaluationFormPolynomial::new(&w);
+        let ones = EvaluationFormPolynomial::new(&vec![Fq::from(1u64); 4]);
+        let f_bc = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![w_i.extend_variables(1, 1), predicates.left]),
+            ProductPolynomial::new(vec![w_i.extend_variables(0, 1), predicates.right]),
+            ProductPolynomial::new(vec![w_i.tensor_product(&w_i), predicates.product]),
+            ProductPolynomial::new(vec![ones, predicates.constant]),
+        ]);
+        let (round_polys, challenges) = gkr_sumcheck::prove_rounds(f_bc, &mut new_transcript());
+
+        let layer_sumcheck = prove_layer(
+            &circuit.layers[1],
+            &eq_table(&r_1),
+            &w,
+            &mut new_transcript(),
+        );
+        assert_eq!(layer_sumcheck.round_polys, round_polys);
+        assert_eq!(
+            [layer_sumcheck.r_b, layer_sumcheck.r_c].concat(),
+            challenges
+        );
+        assert_eq!(layer_sumcheck.w_rb, w_i.evaluate(&challenges[..1]));
+        assert_eq!(layer_sumcheck.w_rc, w_i.evaluate(&challenges[1..]));
+    }
+}
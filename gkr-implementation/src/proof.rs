@@ -0,0 +1,24 @@
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+
+/// A GKR proof for a layered circuit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GkrProof<F: PrimeField> {
+    /// Outputs of the circuit, padded with zeros to a power of two.
+    pub output_mle: Vec<F>,
+    /// Claimed sum and round polynomials of the sumcheck of each layer, from the output layer
+    /// down.
+    pub sumcheck_proof: Vec<(F, Vec<Vec<F>>)>,
+    /// Claimed evaluations `(W(r_b), W(r_c))` of the layer below each sumcheck.
+    pub w_s: Vec<(F, F)>,
+}
+
+/// A GKR proof in which the verifier only sees the inputs through their ML-KZG commitment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommittedGkrProof<P: Pairing> {
+    /// Commitment to the multilinear extension of the inputs, padded to a power of two.
+    pub input_commitment: P::G1,
+    pub gkr_proof: GkrProof<P::ScalarField>,
+    /// Openings of the inputs at the points `r_b` and `r_c` of the last sumcheck.
+    pub input_openings: (Vec<P::G1>, Vec<P::G1>),
+}
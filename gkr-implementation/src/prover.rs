@@ -0,0 +1,149 @@
+use crate::circuit::Circuit;
+use crate::error::GkrError;
+use crate::libra;
+use crate::proof::{CommittedGkrProof, GkrProof};
+use crate::transcript::{
+    new_transcript, serialized_bytes, squeeze_challenges, to_bytes, Transcript,
+};
+use crate::witness::LayeredWitness;
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use ml_kzg::trusted_setup::Tau;
+use multilinear_polynomial::{eq_poly::eq_table, EvaluationFormPolynomial};
+
+/// Proves evaluations of a layered circuit, running the sumcheck of each layer in time linear
+/// in its number of gates and wires.
+#[derive(Debug, Clone, Copy)]
+pub struct GkrProver<'a, F: PrimeField> {
+    circuit: &'a Circuit<F>,
+}
+
+impl<'a, F: PrimeField> GkrProver<'a, F> {
+    pub fn new(circuit: &'a Circuit<F>) -> Self {
+        GkrProver { circuit }
+    }
+
+    /// Proves that `witness`, obtained from [`Circuit::evaluate`], is the evaluation of the
+    /// circuit on its inputs.
+    pub fn prove(&self, witness: &LayeredWitness<F>) -> Result<GkrProof<F>, GkrError> {
+        let mut fiat_shamir = new_transcript();
+        fiat_shamir.absorb(&to_bytes(&self.circuit.padded_inputs(witness.inputs())?));
+        let (gkr_proof, _) = self.prove_layers(witness, &mut fiat_shamir)?;
+        Ok(gkr_proof)
+    }
+
+    /// Proves the evaluation like [`GkrProver::prove`] without sending the inputs: they are
+    /// committed to with ML-KZG under `tau` before any challenge is drawn, and opened at the
+    /// two points the sumcheck of the input layer reduces them to. The proof is not
+    /// zero-knowledge, as its round polynomials and claims still depend on the inputs.
+    pub fn prove_committed<P: Pairing<ScalarField = F>>(
+        &self,
+        witness: &LayeredWitness<F>,
+        tau: &Tau<P>,
+    ) -> Result<CommittedGkrProof<P>, GkrError> {
+        let layer_count = self.circuit.layers.len();
+        if layer_count == 0 {
+            return Err(GkrError::EmptyCircuit);
+        }
+        let input_vars = self.circuit.input_vars(layer_count - 1);
+        if tau.g2_tau.len() != input_vars {
+            return Err(GkrError::SetupSizeMismatch {
+                expected: input_vars,
+                actual: tau.g2_tau.len(),
+            });
+        }
+
+        let inputs = self.circuit.padded_inputs(witness.inputs())?;
+        let input_commitment = ml_kzg::commit::<F, P>(inputs.clone(), tau.lagrange_basis.clone());
+        let mut fiat_shamir = new_transcript();
+        fiat_shamir.absorb(&serialized_bytes(&input_commitment));
+
+        let (gkr_proof, input_point) = self.prove_layers(witness, &mut fiat_shamir)?;
+        let (r_b, r_c) = input_point.split_at(input_vars);
+        let input_openings = (
+            ml_kzg::generate_proofs::<P>(&inputs, r_b, &tau.lagrange_basis),
+            ml_kzg::generate_proofs::<P>(&inputs, r_c, &tau.lagrange_basis),
+        );
+        Ok(CommittedGkrProof {
+            input_commitment,
+            gkr_proof,
+            input_openings,
+        })
+    }
+
+    /// Runs the sumchecks of every layer on `fiat_shamir`, which must already hold the inputs
+    /// or their commitment, returning the proof and the challenges `r_b || r_c` of the last
+    /// one, where the claims on the inputs are made.
+    fn prove_layers(
+        &self,
+        witness: &LayeredWitness<F>,
+        fiat_shamir: &mut Transcript<F>,
+    ) -> Result<(GkrProof<F>, Vec<F>), GkrError> {
+        let circuit = self.circuit;
+        let layer_count = circuit.layers.len();
+        if layer_count == 0 {
+            return Err(GkrError::EmptyCircuit);
+        }
+        if witness.layers.len() != layer_count + 1 {
+            return Err(GkrError::LayerCountMismatch {
+                expected: layer_count,
+                actual: witness.layers.len().saturating_sub(1),
+            });
+        }
+        let widths = std::iter::once(circuit.num_inputs)
+            .chain(circuit.layers.iter().map(|layer| layer.gates.len()));
+        for (layer, (values, expected)) in witness.layers.iter().zip(widths).enumerate() {
+            if values.len() != expected {
+                return Err(GkrError::WitnessWidthMismatch {
+                    layer,
+                    expected,
+                    actual: values.len(),
+                });
+            }
+        }
+        circuit.check_wires()?;
+
+        let output_mle = witness.get_ws(0);
+        fiat_shamir.absorb(&circuit.to_bytes());
+        fiat_shamir.absorb(&to_bytes(&output_mle));
+
+        let r_1 = squeeze_challenges(fiat_shamir, circuit.output_vars(0));
+        let mut claim = EvaluationFormPolynomial::new(&output_mle).evaluate(&r_1);
+
+        let mut gkr_proof = GkrProof {
+            output_mle,
+            sumcheck_proof: vec![],
+            w_s: vec![],
+        };
+
+        // Weights of the claims on the gates of the current layer
+        let mut output_weights = eq_table(&r_1);
+        let mut input_point = vec![];
+        for i in 0..layer_count {
+            // Reduce the claim on layer i to claims on W_{i+1}(r_b) and W_{i+1}(r_c)
+            let layer = &circuit.layers[layer_count - 1 - i];
+            let layer_sumcheck =
+                libra::prove_layer(layer, &output_weights, &witness.get_ws(i + 1), fiat_shamir);
+            let (w_rb, w_rc) = (layer_sumcheck.w_rb, layer_sumcheck.w_rc);
+            gkr_proof
+                .sumcheck_proof
+                .push((claim, layer_sumcheck.round_polys));
+            gkr_proof.w_s.push((w_rb, w_rc));
+
+            // Combine both claims into a single claim on the next layer
+            fiat_shamir.absorb(&to_bytes(&[w_rb]));
+            let alpha = fiat_shamir.squeeze();
+            fiat_shamir.absorb(&to_bytes(&[w_rc]));
+            let beta = fiat_shamir.squeeze();
+            claim = alpha * w_rb + beta * w_rc;
+            output_weights = eq_table(&layer_sumcheck.r_b)
+                .into_iter()
+                .zip(eq_table(&layer_sumcheck.r_c))
+                .map(|(eq_b, eq_c)| alpha * eq_b + beta * eq_c)
+                .collect();
+            input_point = [layer_sumcheck.r_b, layer_sumcheck.r_c].concat();
+        }
+
+        Ok((gkr_proof, input_point))
+    }
+}
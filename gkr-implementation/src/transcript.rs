@@ -0,0 +1,35 @@
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use fiat_shamir::FiatShamir;
+use sha3::{Digest, Sha3_256};
+
+pub(crate) type Transcript<F> = FiatShamir<Sha3_256, F>;
+
+pub(crate) fn new_transcript<F: PrimeField>() -> Transcript<F> {
+    FiatShamir::new(Sha3_256::new())
+}
+
+/// Encodes field elements the way the sumcheck transcripts do, one byte per bit.
+pub(crate) fn to_bytes<F: PrimeField>(values: &[F]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|f| f.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
+        .collect()
+}
+
+/// Squeezes `count` challenges, one after the other.
+pub(crate) fn squeeze_challenges<F: PrimeField>(
+    transcript: &mut Transcript<F>,
+    count: usize,
+) -> Vec<F> {
+    (0..count).map(|_| transcript.squeeze()).collect()
+}
+
+/// Encodes a commitment or other serializable value in its compressed form.
+pub(crate) fn serialized_bytes<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut bytes = vec![];
+    value
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a vector cannot fail");
+    bytes
+}
@@ -0,0 +1,150 @@
+use crate::circuit::Circuit;
+use crate::error::GkrError;
+use crate::proof::{CommittedGkrProof, GkrProof};
+use crate::transcript::{
+    new_transcript, serialized_bytes, squeeze_challenges, to_bytes, Transcript,
+};
+use ark_ec::pairing::Pairing;
+use ark_ff::PrimeField;
+use multilinear_polynomial::EvaluationFormPolynomial;
+use sumcheck::gkr_sumcheck;
+
+/// Verifies GKR proofs for a layered circuit.
+///
+/// The verifier only reads the gate types and wiring of the circuit, never the values on its
+/// wires: every claim about an intermediate layer comes from the proof and is checked against
+/// the transcript, down to the public inputs or to their commitment.
+#[derive(Debug, Clone, Copy)]
+pub struct GkrVerifier<'a, F: PrimeField> {
+    circuit: &'a Circuit<F>,
+}
+
+impl<'a, F: PrimeField> GkrVerifier<'a, F> {
+    pub fn new(circuit: &'a Circuit<F>) -> Self {
+        GkrVerifier { circuit }
+    }
+
+    /// Checks that `gkr_proof` shows the circuit evaluated on `inputs` gives the claimed output.
+    pub fn verify(&self, inputs: &[F], gkr_proof: &GkrProof<F>) -> Result<(), GkrError> {
+        let circuit = self.circuit;
+        let inputs = circuit.padded_inputs(inputs)?;
+        let mut fiat_shamir = new_transcript();
+        fiat_shamir.absorb(&to_bytes(&inputs));
+        let (r_b, r_c) = self.verify_layers(gkr_proof, &mut fiat_shamir)?;
+
+        // The last pair of claims is on the input layer, which the verifier evaluates directly
+        let (w_rb, w_rc) = gkr_proof.w_s[circuit.layers.len() - 1];
+        let input_mle = EvaluationFormPolynomial::new(&inputs);
+        if input_mle.evaluate(&r_b) != w_rb || input_mle.evaluate(&r_c) != w_rc {
+            return Err(GkrError::InputEvaluationMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Checks a proof from [`GkrProver::prove_committed`](crate::GkrProver::prove_committed)
+    /// without seeing the inputs: the last claims on them are checked against the ML-KZG
+    /// openings of their commitment with the verification key `g2_tau`.
+    pub fn verify_committed<P: Pairing<ScalarField = F>>(
+        &self,
+        proof: &CommittedGkrProof<P>,
+        g2_tau: &[P::G2],
+    ) -> Result<(), GkrError> {
+        let mut fiat_shamir = new_transcript();
+        fiat_shamir.absorb(&serialized_bytes(&proof.input_commitment));
+        let (r_b, r_c) = self.verify_layers(&proof.gkr_proof, &mut fiat_shamir)?;
+        if g2_tau.len() != r_b.len() {
+            return Err(GkrError::SetupSizeMismatch {
+                expected: r_b.len(),
+                actual: g2_tau.len(),
+            });
+        }
+
+        let (w_rb, w_rc) = proof.gkr_proof.w_s[self.circuit.layers.len() - 1];
+        let (opening_rb, opening_rc) = &proof.input_openings;
+        let commitment = proof.input_commitment;
+        if !ml_kzg::verify::<P>(commitment, &r_b, w_rb, opening_rb, g2_tau)
+            || !ml_kzg::verify::<P>(commitment, &r_c, w_rc, opening_rc, g2_tau)
+        {
+            return Err(GkrError::InputOpeningFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Checks the sumchecks of every layer on `fiat_shamir`, which must already hold the
+    /// inputs or their commitment, returning the points `(r_b, r_c)` at which the proof makes
+    /// its last claims on the inputs.
+    fn verify_layers(
+        &self,
+        gkr_proof: &GkrProof<F>,
+        fiat_shamir: &mut Transcript<F>,
+    ) -> Result<(Vec<F>, Vec<F>), GkrError> {
+        let circuit = self.circuit;
+        let layer_count = circuit.layers.len();
+        if layer_count == 0 {
+            return Err(GkrError::EmptyCircuit);
+        }
+        if gkr_proof.sumcheck_proof.len() != layer_count || gkr_proof.w_s.len() != layer_count {
+            return Err(GkrError::LayerCountMismatch {
+                expected: layer_count,
+                actual: gkr_proof.sumcheck_proof.len(),
+            });
+        }
+        circuit.check_wires()?;
+        let output_vars = circuit.output_vars(0);
+        if gkr_proof.output_mle.len() != 1 << output_vars {
+            return Err(GkrError::OutputCountMismatch {
+                expected: 1 << output_vars,
+                actual: gkr_proof.output_mle.len(),
+            });
+        }
+
+        // Bind the circuit before any challenge, so a proof cannot be moved to another circuit
+        fiat_shamir.absorb(&circuit.to_bytes());
+        fiat_shamir.absorb(&to_bytes(&gkr_proof.output_mle));
+        let r_1 = squeeze_challenges(fiat_shamir, output_vars);
+        let mut claim = EvaluationFormPolynomial::new(&gkr_proof.output_mle).evaluate(&r_1);
+
+        let (mut r_b, mut r_c) = (vec![], vec![]);
+        let (mut alpha, mut beta) = (F::one(), F::zero());
+        for i in 0..layer_count {
+            let (claimed_sum, round_polys) = &gkr_proof.sumcheck_proof[i];
+            if *claimed_sum != claim {
+                return Err(GkrError::LayerClaimMismatch { layer: i });
+            }
+
+            let num_vars = 2 * circuit.input_vars(i);
+            let (final_claim, mut random_challenges) =
+                gkr_sumcheck::verify_rounds(claim, round_polys, num_vars, 2, fiat_shamir)
+                    .map_err(|error| GkrError::Sumcheck { layer: i, error })?;
+            let next_r_c = random_challenges.split_off(random_challenges.len() / 2);
+            let next_r_b = random_challenges;
+
+            // The wiring predicates are the only part of f(b, c) the verifier evaluates itself
+            let wiring = circuit.wiring_predicate(i);
+            let predicates = if i == 0 {
+                wiring.evaluate(&r_1, &next_r_b, &next_r_c)
+            } else {
+                let at_b = wiring.evaluate(&r_b, &next_r_b, &next_r_c);
+                let at_c = wiring.evaluate(&r_c, &next_r_b, &next_r_c);
+                at_b.zip_with(at_c, |b, c| alpha * b + beta * c)
+            };
+
+            let (w_rb, w_rc) = gkr_proof.w_s[i];
+            if final_claim != predicates.combine(w_rb, w_rc) {
+                return Err(GkrError::WiringCheckFailed { layer: i });
+            }
+
+            fiat_shamir.absorb(&to_bytes(&[w_rb]));
+            alpha = fiat_shamir.squeeze();
+            fiat_shamir.absorb(&to_bytes(&[w_rc]));
+            beta = fiat_shamir.squeeze();
+            claim = alpha * w_rb + beta * w_rc;
+            r_b = next_r_b;
+            r_c = next_r_c;
+        }
+
+        Ok((r_b, r_c))
+    }
+}
@@ -0,0 +1,153 @@
+use crate::circuit::CustomGate;
+use ark_ff::PrimeField;
+use multilinear_polynomial::eq_poly::eq_table;
+
+/// Wiring predicates of a layer weighted by the coefficients of its gates: `left(a, b, c)` is
+/// the `left` coefficient of gate `a` if it reads wires `b` and `c` and zero otherwise, and
+/// likewise for the others.
+///
+/// Summing the term `pred_g(a, b, c) * g(W(b), W(c))` of every gate type `g` gives the layer's
+/// sumcheck polynomial, which groups into
+/// `f(b, c) = left * W(b) + right * W(c) + product * W(b) * W(c) + constant`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WiringPredicates<T> {
+    pub left: T,
+    pub right: T,
+    pub product: T,
+    pub constant: T,
+}
+
+impl<T> WiringPredicates<T> {
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> WiringPredicates<U> {
+        WiringPredicates {
+            left: f(self.left),
+            right: f(self.right),
+            product: f(self.product),
+            constant: f(self.constant),
+        }
+    }
+
+    pub fn zip_with<U, V>(
+        self,
+        other: WiringPredicates<U>,
+        f: impl Fn(T, U) -> V,
+    ) -> WiringPredicates<V> {
+        WiringPredicates {
+            left: f(self.left, other.left),
+            right: f(self.right, other.right),
+            product: f(self.product, other.product),
+            constant: f(self.constant, other.constant),
+        }
+    }
+}
+
+impl<F: PrimeField> WiringPredicates<F> {
+    /// Value of `f(b, c)` given the evaluations of the predicates and of `W(b)` and `W(c)`.
+    pub fn combine(&self, w_b: F, w_c: F) -> F {
+        self.left * w_b + self.right * w_c + self.product * w_b * w_c + self.constant
+    }
+}
+
+/// Sparse wiring predicate of a layer: the `(out, left, right)` triple of every gate with its
+/// coefficients, over `output_vars` variables indexing the gates and `input_vars` variables
+/// indexing the wires they read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WiringPredicate<F: PrimeField> {
+    pub output_vars: usize,
+    pub input_vars: usize,
+    pub triples: Vec<(usize, usize, usize)>,
+    pub coefficients: Vec<CustomGate<F>>,
+}
+
+impl<F: PrimeField> WiringPredicate<F> {
+    /// Evaluates the multilinear extensions of the predicates at `(r_a, r_b, r_c)`, in time
+    /// linear in the number of gates and wires rather than in the `2^(a + 2b)` points of the
+    /// dense predicates.
+    pub fn evaluate(&self, r_a: &[F], r_b: &[F], r_c: &[F]) -> WiringPredicates<F> {
+        assert_eq!(r_a.len(), self.output_vars, "r_a must index the gates");
+        assert_eq!(r_b.len(), self.input_vars, "r_b must index the wires");
+        assert_eq!(r_c.len(), self.input_vars, "r_c must index the wires");
+
+        let (eq_a, eq_b, eq_c) = (eq_table(r_a), eq_table(r_b), eq_table(r_c));
+        let mut evaluations = WiringPredicates {
+            left: F::zero(),
+            right: F::zero(),
+            product: F::zero(),
+            constant: F::zero(),
+        };
+        for (&(out, left, right), coefficients) in self.triples.iter().zip(&self.coefficients) {
+            let eq = eq_a[out] * eq_b[left] * eq_c[right];
+            evaluations.left += eq * coefficients.left;
+            evaluations.right += eq * coefficients.right;
+            evaluations.product += eq * coefficients.product;
+            evaluations.constant += eq * coefficients.constant;
+        }
+        evaluations
+    }
+
+    /// The dense evaluations of the predicates over all `2^(a + 2b)` points, with the output
+    /// gate as the most significant variables.
+    pub fn to_dense(&self) -> WiringPredicates<Vec<F>> {
+        let len = 1 << (self.output_vars + 2 * self.input_vars);
+        let mut dense = WiringPredicates {
+            left: vec![F::zero(); len],
+            right: vec![F::zero(); len],
+            product: vec![F::zero(); len],
+            constant: vec![F::zero(); len],
+        };
+        for (&(out, left, right), coefficients) in self.triples.iter().zip(&self.coefficients) {
+            let index = (((out << self.input_vars) | left) << self.input_vars) | right;
+            dense.left[index] += coefficients.left;
+            dense.right[index] += coefficients.right;
+            dense.product[index] += coefficients.product;
+            dense.constant[index] += coefficients.constant;
+        }
+        dense
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::Op;
+    use ark_bn254::Fq;
+    use multilinear_polynomial::EvaluationFormPolynomial;
+
+    fn test_predicate() -> WiringPredicate<Fq> {
+        WiringPredicate {
+            output_vars: 1,
+            input_vars: 2,
+            triples: vec![(0, 0, 1), (1, 3, 3)],
+            coefficients: vec![
+                Op::Sub.coefficients(),
+                Op::AddConst(Fq::from(7u64)).coefficients(),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_evaluate_matches_dense() {
+        let wiring = test_predicate();
+        let (r_a, r_b, r_c) = (
+            [Fq::from(3u64)],
+            [Fq::from(5u64), Fq::from(8u64)],
+            [Fq::from(13u64), Fq::from(21u64)],
+        );
+        let point = [&r_a[..], &r_b, &r_c].concat();
+        let dense = wiring
+            .to_dense()
+            .map(|pred| EvaluationFormPolynomial::new(&pred).evaluate(&point));
+        assert_eq!(wiring.evaluate(&r_a, &r_b, &r_c), dense);
+    }
+
+    #[test]
+    fn test_evaluate_on_hypercube() {
+        let (zero, one) = (Fq::from(0u64), Fq::from(1u64));
+        let at_gate = test_predicate().evaluate(&[one], &[one, one], &[one, one]);
+        assert_eq!(at_gate.left, one);
+        assert_eq!(at_gate.constant, Fq::from(7u64));
+
+        let off_gate = test_predicate().evaluate(&[one], &[zero, zero], &[zero, one]);
+        assert_eq!(off_gate.combine(Fq::from(2u64), Fq::from(3u64)), zero);
+    }
+}
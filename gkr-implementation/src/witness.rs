@@ -0,0 +1,31 @@
+use crate::circuit::wire_vars;
+use ark_ff::PrimeField;
+
+/// Values on every wire of a [`Circuit`](crate::Circuit) evaluated on some inputs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayeredWitness<F: PrimeField> {
+    /// `layers[0]` holds the circuit inputs and `layers[i + 1]` the outputs of circuit layer `i`.
+    pub layers: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> LayeredWitness<F> {
+    pub fn inputs(&self) -> &[F] {
+        &self.layers[0]
+    }
+
+    pub fn outputs(&self) -> &[F] {
+        &self.layers[self.layers.len() - 1]
+    }
+
+    /// Values `W_layer` on the wires of `layer`, counted from the output layer, padded with
+    /// zeros to a power of two. `W_0` holds the circuit outputs and the last one the inputs.
+    pub(crate) fn get_ws(&self, layer: usize) -> Vec<F> {
+        let mut w = self.layers[self.layers.len() - 1 - layer].clone();
+        if layer == 0 {
+            w.resize(w.len().next_power_of_two(), F::zero());
+        } else {
+            w.resize(1 << wire_vars(w.len()), F::zero());
+        }
+        w
+    }
+}
@@ -1,10 +1,10 @@
 use ark_ff::{PrimeField, Zero};
 use ark_ec::{pairing::Pairing, PrimeGroup};
 use multilinear_polynomial::EvaluationFormPolynomial;
-mod trusted_setup;
+pub mod trusted_setup;
 
 
-fn commit<F: PrimeField, P:Pairing>(poly_values:Vec<F>,  lagrange_basis:Vec<<P>::G1>)-> <P>::G1{    
+pub fn commit<F: PrimeField, P:Pairing>(poly_values:Vec<F>,  lagrange_basis:Vec<<P>::G1>)-> <P>::G1{    
     assert_eq!(poly_values.len(), lagrange_basis.len(), "len of values of poly should be equal to len of lagrange basis");
     let mut sum = P::G1::zero();
     for i in 0..poly_values.len(){
@@ -12,61 +12,73 @@ fn commit<F: PrimeField, P:Pairing>(poly_values:Vec<F>,  lagrange_basis:Vec<<P>:
     }
     sum
 }
-fn open<F:PrimeField>(poly_values:&Vec<F>, open_values:&Vec<F>)-> F{
+pub fn open<F:PrimeField>(poly_values:&[F], open_values:&[F])-> F{
     let variables_len: F = F::from(open_values.len() as u64);
     assert_eq!(F::from(poly_values.len() as  u64), F::from(2).pow(variables_len.into_bigint()));
-    let mut partial_evaluation = F::zero();
-    let mut poly = EvaluationFormPolynomial::new(&poly_values);
-    for i in 0..open_values.len(){
-        poly = poly.partial_evaluate(open_values[i], 0);
-   
-    }
-    
-    poly.representation[0]   
+    let poly = EvaluationFormPolynomial::new(&poly_values.to_vec());
+    poly.evaluate(open_values)
 
 }
 
-pub fn blowup_sub<F:PrimeField>(poly1:&Vec<F>, poly2:&Vec<F>) -> EvaluationFormPolynomial<F> {    
-     let mut result =  vec![];
-        for i in 0..poly1.len() {
-            let first_poly = poly1[i];
-            for j in 0..poly2.len() {
-                let second_poly = first_poly + poly2[j];
-                result.push(second_poly);
-            }
-        }
-          EvaluationFormPolynomial::new(&result)
+/// Proves the evaluation of the polynomial at `open_values`, returning commitments to the
+/// quotients `q_i` in `f(x) - f(r) = sum_i (x_i - r_i) * q_i(x_{i+1}, ..., x_{n-1})`.
+pub fn generate_proofs<P: Pairing>(
+    poly_values: &[P::ScalarField],
+    open_values: &[P::ScalarField],
+    lagrange_basis: &[P::G1],
+) -> Vec<P::G1> {
+    assert_eq!(
+        poly_values.len(),
+        1 << open_values.len(),
+        "one open value is needed per variable"
+    );
+    assert_eq!(
+        poly_values.len(),
+        lagrange_basis.len(),
+        "len of values of poly should be equal to len of lagrange basis"
+    );
+
+    let mut poly = poly_values.to_vec();
+    let mut basis = lagrange_basis.to_vec();
+    let mut proofs = Vec::with_capacity(open_values.len());
+    for open_value in open_values {
+        let half = poly.len() / 2;
+        // Summing out the leading variable leaves the lagrange basis of the remaining ones
+        basis = (0..half).map(|j| basis[j] + basis[j + half]).collect();
+
+        let quotient: Vec<P::ScalarField> = (0..half).map(|j| poly[j + half] - poly[j]).collect();
+        proofs.push(commit::<P::ScalarField, P>(quotient.clone(), basis.clone()));
+
+        poly = (0..half).map(|j| poly[j] + *open_value * quotient[j]).collect();
     }
+    proofs
+}
 
-fn generate_proofs<F:PrimeField>(poly_values:&Vec<F>, open_values:&Vec<F>){
-    let open = open(poly_values, open_values);
-    let poly_minus_open = poly_values.iter().map(|x| *x - open).collect::<Vec<F>>();
-    let mut poly = EvaluationFormPolynomial::new(&poly_minus_open);
-    // let mid = poly.representation.len() / 2;
-    // let (first_half, second_half) = poly.representation.split_at(mid);
-
-    // let univariate_polynomial: Vec<F> = vec![first_half_sum, second_half_sum];
-    let mut quotient_evals_vec = vec![];
-        for j in 0..open_values.len() -1{
-            
-            let mut eval_at_1 = poly.partial_evaluate(F::from(1), 0);
-            let eval_at_0 = eval_at_1.partial_evaluate(F::from(0), 0);
-            // println!("eval_at_1 {:?} j {}",  eval_at_1, j);
-            // println!("eval_at_0  {:?} j {}",  eval_at_0, j);
-         
-            let mut quotient_poly = blowup_sub(&eval_at_1.representation, &eval_at_0.representation);
-            println!("quotient_poly {:?}", quotient_poly);
-
-            let quotient_eval = quotient_poly.partial_evaluate(open_values[j], 0);  
-            quotient_evals_vec.push(quotient_eval);
-
-            poly = poly.partial_evaluate(open_values[j], 0);
-        }
-        println!("quotient_evals_vec {:?}", quotient_evals_vec);
-
-
-
+/// Checks `proofs` from [`generate_proofs`] showing the polynomial committed to in
+/// `commitment` takes `value` at `open_values`, with the pairing check
+/// `e(C - [v], [1]) == prod_i e([q_i], [tau_i - r_i])`.
+pub fn verify<P: Pairing>(
+    commitment: P::G1,
+    open_values: &[P::ScalarField],
+    value: P::ScalarField,
+    proofs: &[P::G1],
+    g2_tau: &[P::G2],
+) -> bool {
+    if proofs.len() != open_values.len() || g2_tau.len() != open_values.len() {
+        return false;
+    }
+    let generator1 = P::G1::generator();
+    let generator2 = P::G2::generator();
+
+    let lhs = P::pairing(commitment - generator1 * value, generator2);
+    let shifted_taus = g2_tau
+        .iter()
+        .zip(open_values)
+        .map(|(tau, open_value)| *tau - generator2 * open_value);
+    let rhs = P::multi_pairing(proofs.iter().copied(), shifted_taus);
+    lhs == rhs
 }
+
 #[cfg(test)]
 mod tests {
     use crate::trusted_setup::Tau;
@@ -91,24 +103,25 @@ mod tests {
 
 
     }
-     #[test]
-     fn test_generate_proofs(){
-    let poly_values = vec![ 
-                Fq::from(0),
-                Fq::from(4),
-                Fq::from(0),
-                Fq::from(4),
-                Fq::from(0),
-                Fq::from(4),
-                Fq::from(3),
-                Fq::from(7),];
-        let open_values = vec![ 
-                Fq::from(6),
-                Fq::from(4),
-                Fq::from(0),];
-        let open = generate_proofs(&poly_values, &open_values);
-        // assert_eq!(open, Fq::from(72));
-     }
+    #[test]
+    fn test_generate_proofs() {
+        use ark_bn254::{Bn254, Fr};
+
+        let poly_values: Vec<Fr> = [0u64, 4, 0, 4, 0, 4, 3, 7].map(Fr::from).to_vec();
+        let open_values: Vec<Fr> = [6u64, 4, 0].map(Fr::from).to_vec();
+        let tau = Tau::<Bn254>::initialise([5u64, 7, 11].map(Fr::from).to_vec());
+
+        let commitment = commit::<Fr, Bn254>(poly_values.clone(), tau.lagrange_basis.clone());
+        let value = open(&poly_values, &open_values);
+        let proofs = generate_proofs::<Bn254>(&poly_values, &open_values, &tau.lagrange_basis);
+        assert_eq!(proofs.len(), 3);
+        assert!(verify::<Bn254>(commitment, &open_values, value, &proofs, &tau.g2_tau));
+
+        let wrong_value = value + Fr::from(1);
+        assert!(!verify::<Bn254>(commitment, &open_values, wrong_value, &proofs, &tau.g2_tau));
+        let other_point: Vec<Fr> = [6u64, 4, 1].map(Fr::from).to_vec();
+        assert!(!verify::<Bn254>(commitment, &other_point, value, &proofs, &tau.g2_tau));
+    }
      #[test]
     fn test_open(){
         let poly_values = vec![ 
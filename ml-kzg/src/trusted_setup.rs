@@ -16,21 +16,20 @@ impl<P:Pairing> Tau<P> {
 
         
         let no_of_variables = taus.len();
-        let hypercube = boolean_hypercube::<F>(no_of_variables).into_iter().collect::<Vec<_>>();
+        let hypercube = boolean_hypercube(no_of_variables);
         let mut lagrange_basis = Vec::new();
-        for point in &hypercube {
+        for point in hypercube.iter() {
             let mut value = F::one();
-            for (i, bit) in point.chars().enumerate() {
-            if bit == '1' {
-                value *= taus[i];
+            for (i, tau) in taus.iter().enumerate() {
+            if point.bit(i) {
+                value *= tau;
             } else {
-                value *= F::one() - taus[i];
+                value *= F::one() - tau;
             }
             }
             lagrange_basis.push(generator1.mul_bigint(value.into_bigint()));
         }
-        let mut g2_tau = Vec::new();
-        g2_tau = taus.iter().map(|i| generator2.mul_bigint(i.into_bigint())).collect::<Vec<_>>();
+        let g2_tau = taus.iter().map(|i| generator2.mul_bigint(i.into_bigint())).collect::<Vec<_>>();
      
         // println!("lagrange_basis {:?}", lagrange_basis);
         // println!("g2_tau {:?}", g2_tau);
@@ -0,0 +1,179 @@
+use crate::error::PolynomialError;
+use crate::EvaluationFormPolynomial;
+use ark_ff::PrimeField;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<F: PrimeField> EvaluationFormPolynomial<F> {
+    fn check_same_variables(&self, other: &Self) -> Result<(), PolynomialError> {
+        if self.representation.len() != other.representation.len() {
+            return Err(PolynomialError::VariableCountMismatch {
+                left: self.num_vars,
+                right: other.num_vars,
+            });
+        }
+        Ok(())
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(F, F) -> F) -> Result<Self, PolynomialError> {
+        self.check_same_variables(other)?;
+        let representation = self
+            .representation
+            .iter()
+            .zip(&other.representation)
+            .map(|(a, b)| op(*a, *b))
+            .collect();
+        Ok(EvaluationFormPolynomial {
+            representation,
+            num_vars: self.num_vars,
+        })
+    }
+
+    /// Adds two polynomials, failing if they are over a different number of variables.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    /// Subtracts two polynomials, failing if they are over a different number of variables.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Multiplies two polynomials pointwise on the hypercube (Hadamard product), failing if
+    /// they are over a different number of variables. The result is the multilinear
+    /// extension of the product, not the degree-2 product polynomial itself.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    /// Multiplies every evaluation by `scalar`.
+    pub fn scale(&self, scalar: F) -> Self {
+        EvaluationFormPolynomial {
+            representation: self.representation.iter().map(|v| *v * scalar).collect(),
+            num_vars: self.num_vars,
+        }
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $checked:ident, $assign_trait:ident, $assign_method:ident) => {
+        impl<F: PrimeField> $trait<&EvaluationFormPolynomial<F>> for &EvaluationFormPolynomial<F> {
+            type Output = EvaluationFormPolynomial<F>;
+
+            fn $method(self, other: &EvaluationFormPolynomial<F>) -> EvaluationFormPolynomial<F> {
+                self.$checked(other).unwrap()
+            }
+        }
+
+        impl<F: PrimeField> $trait for EvaluationFormPolynomial<F> {
+            type Output = EvaluationFormPolynomial<F>;
+
+            fn $method(self, other: EvaluationFormPolynomial<F>) -> EvaluationFormPolynomial<F> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<F: PrimeField> $assign_trait<&EvaluationFormPolynomial<F>>
+            for EvaluationFormPolynomial<F>
+        {
+            fn $assign_method(&mut self, other: &EvaluationFormPolynomial<F>) {
+                *self = (&*self).$method(other);
+            }
+        }
+
+        impl<F: PrimeField> $assign_trait for EvaluationFormPolynomial<F> {
+            fn $assign_method(&mut self, other: EvaluationFormPolynomial<F>) {
+                *self = (&*self).$method(&other);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, checked_add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, checked_sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, checked_mul, MulAssign, mul_assign);
+
+impl<F: PrimeField> Mul<F> for EvaluationFormPolynomial<F> {
+    type Output = EvaluationFormPolynomial<F>;
+
+    fn mul(self, scalar: F) -> EvaluationFormPolynomial<F> {
+        self.scale(scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &EvaluationFormPolynomial<F> {
+    type Output = EvaluationFormPolynomial<F>;
+
+    fn mul(self, scalar: F) -> EvaluationFormPolynomial<F> {
+        self.scale(scalar)
+    }
+}
+
+impl<F: PrimeField> MulAssign<F> for EvaluationFormPolynomial<F> {
+    fn mul_assign(&mut self, scalar: F) {
+        self.representation.iter_mut().for_each(|v| *v *= scalar);
+    }
+}
+
+impl<F: PrimeField> Neg for EvaluationFormPolynomial<F> {
+    type Output = EvaluationFormPolynomial<F>;
+
+    fn neg(mut self) -> EvaluationFormPolynomial<F> {
+        self.representation.iter_mut().for_each(|v| *v = -*v);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(values: &[u64]) -> EvaluationFormPolynomial<Fq> {
+        EvaluationFormPolynomial::new(&values.iter().map(|v| Fq::from(*v)).collect())
+    }
+
+    #[test]
+    fn test_arithmetic_ops() {
+        let a = poly(&[1, 2, 3, 4]);
+        let b = poly(&[5, 6, 7, 8]);
+
+        assert_eq!(&a + &b, poly(&[6, 8, 10, 12]));
+        assert_eq!(&b - &a, poly(&[4, 4, 4, 4]));
+        assert_eq!(&a * &b, poly(&[5, 12, 21, 32]));
+        assert_eq!(a.clone() * Fq::from(3), poly(&[3, 6, 9, 12]));
+        assert_eq!(-a.clone() + a.clone(), poly(&[0, 0, 0, 0]));
+
+        let mut c = a.clone();
+        c += &b;
+        c -= a.clone();
+        c *= Fq::from(2);
+        assert_eq!(c, poly(&[10, 12, 14, 16]));
+        c *= &a;
+        assert_eq!(c, poly(&[10, 24, 42, 64]));
+
+        // The Hadamard product agrees with the product of evaluations on the hypercube
+        let point = [Fq::from(0), Fq::from(1)];
+        assert_eq!(
+            (&a * &b).evaluate(&point),
+            a.evaluate(&point) * b.evaluate(&point)
+        );
+    }
+
+    #[test]
+    fn test_variable_count_mismatch() {
+        let a = poly(&[1, 2, 3, 4]);
+        let b = poly(&[1, 2]);
+        assert_eq!(
+            a.checked_add(&b),
+            Err(PolynomialError::VariableCountMismatch { left: 2, right: 1 })
+        );
+        assert!(a.checked_sub(&b).is_err());
+        assert!(a.checked_mul(&b).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_mismatch_panics() {
+        let _ = poly(&[1, 2, 3, 4]) + poly(&[1, 2]);
+    }
+}
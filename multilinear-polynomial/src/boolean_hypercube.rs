@@ -1,27 +1,112 @@
-use ark_ff::PrimeField;
-pub fn boolean_hypercube<F: PrimeField>(no_of_variables: usize) -> Vec<String> {
-    let length_of_hypercube = 2_usize.pow(no_of_variables as u32);
+/// The boolean hypercube `{0, 1}^n`.
+///
+/// Points are identified by their integer index in `0..2^n`. Variable `0` is the most
+/// significant bit of the index, so iterating the indices in order visits the points in the
+/// same order as the evaluations of an `EvaluationFormPolynomial`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BooleanHypercube {
+    pub no_of_variables: usize,
+}
+
+/// A single point of the boolean hypercube, stored as its index and the number of variables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HypercubePoint {
+    pub index: usize,
+    pub no_of_variables: usize,
+}
+
+impl BooleanHypercube {
+    pub fn new(no_of_variables: usize) -> Self {
+        BooleanHypercube { no_of_variables }
+    }
+
+    /// Number of points on the hypercube, `2^n`.
+    pub fn len(&self) -> usize {
+        1 << self.no_of_variables
+    }
+
+    /// A hypercube always contains at least one point.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = HypercubePoint> {
+        let no_of_variables = self.no_of_variables;
+        (0..self.len()).map(move |index| HypercubePoint {
+            index,
+            no_of_variables,
+        })
+    }
+}
 
-    let hypercube_indices: Vec<usize> = (0..length_of_hypercube).collect();
+impl HypercubePoint {
+    /// Value of the coordinate at `position` (variable `0` is the most significant bit).
+    pub fn bit(&self, position: usize) -> bool {
+        bit_at(self.index, position, self.no_of_variables)
+    }
+
+    /// All coordinates of the point, from variable `0` to variable `n - 1`.
+    pub fn bits(&self) -> Vec<bool> {
+        (0..self.no_of_variables).map(|i| self.bit(i)).collect()
+    }
+}
+
+/// Creates the boolean hypercube over `no_of_variables` variables.
+pub fn boolean_hypercube(no_of_variables: usize) -> BooleanHypercube {
+    BooleanHypercube::new(no_of_variables)
+}
 
-    let result: Vec<String> = (0..hypercube_indices.len())
-        .map(|i| format!("{:0width$b}", i, width = no_of_variables))
-        .collect();
+/// Returns the coordinate at `position` of the hypercube point `index`, where variable `0`
+/// is the most significant of `no_of_variables` bits.
+pub fn bit_at(index: usize, position: usize, no_of_variables: usize) -> bool {
+    assert!(position < no_of_variables, "position out of range");
+    (index >> (no_of_variables - 1 - position)) & 1 == 1
+}
 
-    result
+/// Removes the coordinate at `position` from the hypercube point `index`, returning the index
+/// of the resulting point on the hypercube with one fewer variable.
+pub fn remove_bit(index: usize, position: usize, no_of_variables: usize) -> usize {
+    assert!(position < no_of_variables, "position out of range");
+    let shift = no_of_variables - 1 - position;
+    let low = index & ((1 << shift) - 1);
+    let high = index >> (shift + 1);
+    (high << shift) | low
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fq;
     #[test]
     fn test_boolean_hypercube() {
-        let result_2: Vec<String> = boolean_hypercube::<Fq>(2);
-        let result: Vec<String> = boolean_hypercube::<Fq>(3);
+        let result_2: Vec<usize> = boolean_hypercube(2).iter().map(|p| p.index).collect();
+        let result: Vec<Vec<bool>> = boolean_hypercube(3).iter().map(|p| p.bits()).collect();
         assert_eq!(
             result,
-            vec!["000", "001", "010", "011", "100", "101", "110", "111"]
+            vec![
+                vec![false, false, false],
+                vec![false, false, true],
+                vec![false, true, false],
+                vec![false, true, true],
+                vec![true, false, false],
+                vec![true, false, true],
+                vec![true, true, false],
+                vec![true, true, true],
+            ]
         );
-        assert_eq!(result_2, vec!["00", "01", "10", "11"]);
+        assert_eq!(result_2, vec![0, 1, 2, 3]);
+        assert_eq!(boolean_hypercube(3).len(), 8);
+    }
+
+    #[test]
+    fn test_bit_operations() {
+        // 0b110 over three variables is the point (1, 1, 0)
+        assert!(bit_at(0b110, 0, 3));
+        assert!(bit_at(0b110, 1, 3));
+        assert!(!bit_at(0b110, 2, 3));
+
+        assert_eq!(remove_bit(0b110, 0, 3), 0b10);
+        assert_eq!(remove_bit(0b110, 1, 3), 0b10);
+        assert_eq!(remove_bit(0b110, 2, 3), 0b11);
+        assert_eq!(remove_bit(0b1011, 1, 4), 0b111);
     }
 }
@@ -0,0 +1,127 @@
+use crate::{EvaluationFormPolynomial, MultilinearPolynomialSparse};
+use ark_ff::PrimeField;
+
+/// Zeta transform over the subset lattice: turns monomial coefficients indexed by the set of
+/// variables they contain into evaluations on the boolean hypercube.
+fn zeta_transform<F: PrimeField>(values: &mut [F], num_vars: usize) {
+    for i in 0..num_vars {
+        let bit = 1 << i;
+        for index in 0..values.len() {
+            if index & bit != 0 {
+                let lower = values[index ^ bit];
+                values[index] += lower;
+            }
+        }
+    }
+}
+
+/// Möbius transform, the inverse of `zeta_transform`: turns evaluations on the boolean
+/// hypercube into monomial coefficients.
+fn mobius_transform<F: PrimeField>(values: &mut [F], num_vars: usize) {
+    for i in 0..num_vars {
+        let bit = 1 << i;
+        for index in 0..values.len() {
+            if index & bit != 0 {
+                let lower = values[index ^ bit];
+                values[index] -= lower;
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> MultilinearPolynomialSparse<F> {
+    /// Converts the polynomial to its evaluations on the boolean hypercube in `O(n * 2^n)`.
+    /// Variable `0` of each monomial maps to the most significant bit of the hypercube index.
+    pub fn to_evaluation_form(&self) -> EvaluationFormPolynomial<F> {
+        let num_vars = self.num_vars;
+        let mut values = vec![F::zero(); 1 << num_vars];
+        for (coeff, vars) in &self.polynomial {
+            let index = vars
+                .iter()
+                .fold(0, |index, var| (index << 1) | !var.is_zero() as usize);
+            values[index] += coeff;
+        }
+
+        zeta_transform(&mut values, num_vars);
+        EvaluationFormPolynomial::new(&values)
+    }
+
+    /// Recovers the monomial coefficients of a polynomial from its evaluations on the boolean
+    /// hypercube in `O(n * 2^n)`. Monomials with a zero coefficient are omitted.
+    pub fn from_evaluation_form(poly: &EvaluationFormPolynomial<F>) -> Self {
+        let num_vars = poly.num_vars;
+        let mut values = poly.representation.clone();
+        mobius_transform(&mut values, num_vars);
+
+        let polynomial = poly
+            .hypercube()
+            .iter()
+            .zip(values)
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(point, coeff)| {
+                let vars = point.bits().into_iter().map(F::from).collect();
+                (coeff, vars)
+            })
+            .collect();
+
+        MultilinearPolynomialSparse::new(num_vars, polynomial)
+    }
+}
+
+impl<F: PrimeField> From<&MultilinearPolynomialSparse<F>> for EvaluationFormPolynomial<F> {
+    fn from(poly: &MultilinearPolynomialSparse<F>) -> Self {
+        poly.to_evaluation_form()
+    }
+}
+
+impl<F: PrimeField> From<&EvaluationFormPolynomial<F>> for MultilinearPolynomialSparse<F> {
+    fn from(poly: &EvaluationFormPolynomial<F>) -> Self {
+        MultilinearPolynomialSparse::from_evaluation_form(poly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_sparse_to_evaluation_form() {
+        // f(a, b, c) = 3bc + 4ab + 5abc + 2
+        let poly = MultilinearPolynomialSparse::new(
+            3,
+            vec![
+                (Fq::from(3), vec![Fq::from(0), Fq::from(1), Fq::from(1)]),
+                (Fq::from(4), vec![Fq::from(1), Fq::from(1), Fq::from(0)]),
+                (Fq::from(5), vec![Fq::from(1), Fq::from(1), Fq::from(1)]),
+                (Fq::from(2), vec![Fq::from(0), Fq::from(0), Fq::from(0)]),
+            ],
+        );
+        let evaluation_form = poly.to_evaluation_form();
+        assert_eq!(
+            evaluation_form.representation,
+            vec![2u64, 2, 2, 5, 2, 2, 6, 14]
+                .into_iter()
+                .map(Fq::from)
+                .collect::<Vec<_>>()
+        );
+
+        let point = [Fq::from(5), Fq::from(7), Fq::from(11)];
+        assert_eq!(
+            evaluation_form.evaluate(&point),
+            Fq::from(3 * 7 * 11 + 4 * 5 * 7 + 5 * 5 * 7 * 11 + 2)
+        );
+    }
+
+    #[test]
+    fn test_evaluation_form_round_trip() {
+        let values: Vec<Fq> = vec![7u64, 4, 2, 6, 1, 5, 3, 15]
+            .into_iter()
+            .map(Fq::from)
+            .collect();
+        let poly = EvaluationFormPolynomial::new(&values);
+
+        let sparse = MultilinearPolynomialSparse::from(&poly);
+        assert_eq!(EvaluationFormPolynomial::from(&sparse), poly);
+    }
+}
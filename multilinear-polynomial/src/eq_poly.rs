@@ -0,0 +1,55 @@
+use ark_ff::PrimeField;
+
+/// Builds the table of `eq(r, x)` for every point `x` of the boolean hypercube, where
+/// `eq(r, x) = ∏ (r_i * x_i + (1 - r_i) * (1 - x_i))`. The table is laid out in the same
+/// order as an `EvaluationFormPolynomial`, with `r[0]` bound to the most significant bit.
+pub fn eq_table<F: PrimeField>(r: &[F]) -> Vec<F> {
+    let mut table = Vec::with_capacity(1 << r.len());
+    table.push(F::one());
+    for r_i in r {
+        let mut next = Vec::with_capacity(table.len() * 2);
+        for t in &table {
+            let hi = *t * r_i;
+            next.push(*t - hi);
+            next.push(hi);
+        }
+        table = next;
+    }
+    table
+}
+
+/// Evaluates `eq(x, y)` for two points of the same length.
+pub fn eq_eval<F: PrimeField>(x: &[F], y: &[F]) -> F {
+    assert_eq!(
+        x.len(),
+        y.len(),
+        "points must have the same number of variables"
+    );
+    x.iter()
+        .zip(y)
+        .map(|(x_i, y_i)| *x_i * y_i + (F::one() - x_i) * (F::one() - y_i))
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_eq_table() {
+        let r = vec![Fq::from(3), Fq::from(5)];
+        let table = eq_table(&r);
+        assert_eq!(
+            table,
+            vec![
+                (Fq::from(1) - Fq::from(3)) * (Fq::from(1) - Fq::from(5)),
+                (Fq::from(1) - Fq::from(3)) * Fq::from(5),
+                Fq::from(3) * (Fq::from(1) - Fq::from(5)),
+                Fq::from(15),
+            ]
+        );
+        assert_eq!(table.iter().copied().sum::<Fq>(), Fq::from(1));
+        assert_eq!(table[2], eq_eval(&r, &[Fq::from(1), Fq::from(0)]));
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors returned by operations on multilinear polynomials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolynomialError {
+    /// Two polynomials were combined but are defined over a different number of variables.
+    VariableCountMismatch { left: usize, right: usize },
+    /// More evaluations were given than fit on the hypercube over `num_vars` variables.
+    TooManyEvaluations { len: usize, num_vars: usize },
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::VariableCountMismatch { left, right } => write!(
+                f,
+                "polynomials have a different number of variables: {} and {}",
+                left, right
+            ),
+            PolynomialError::TooManyEvaluations { len, num_vars } => write!(
+                f,
+                "{} evaluations do not fit on a hypercube over {} variables",
+                len, num_vars
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PolynomialError {}
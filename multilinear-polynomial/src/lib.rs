@@ -1,33 +1,39 @@
 use ark_ff::PrimeField;
 use std::vec;
+mod arithmetic;
 pub mod boolean_hypercube;
+mod conversion;
+pub mod eq_poly;
+pub mod error;
 pub mod product_poly;
+pub mod sparse;
+pub mod streaming;
+mod tensor;
+mod variables;
+pub mod virtual_poly;
 use boolean_hypercube::*;
+use eq_poly::eq_table;
+use error::PolynomialError;
+pub use sparse::MultilinearPolynomialSparse;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct EvaluationFormPolynomial<F: PrimeField> {
     pub representation: Vec<F>,
-    pub hypercube: Vec<String>,
+    pub num_vars: usize,
 }
 
 
-impl<F: PrimeField> EvaluationFormPolynomial<F> {
-    pub fn default() -> Self {
+impl<F: PrimeField> Default for EvaluationFormPolynomial<F> {
+    fn default() -> Self {
         EvaluationFormPolynomial {
             representation: vec![],
-            hypercube: vec![],
-        }
-    }
-
-    
-    pub fn add(&self, other: Self) -> Self {
-        let mut result = self.clone();
-        for (i, coeff) in other.representation.iter().enumerate() {
-            result.representation[i] += coeff;
+            num_vars: 0,
         }
-        result
     }
+}
 
+impl<F: PrimeField> EvaluationFormPolynomial<F> {
+    #[allow(clippy::ptr_arg)]
     pub fn new(values: &Vec<F>) -> Self {
         // Check if the length of values is a power of 2
         assert!(
@@ -35,100 +41,162 @@ impl<F: PrimeField> EvaluationFormPolynomial<F> {
             "Length of values must be a power of 2"
         );
 
-        let value = values.len();
-        let hypercube_size = value.ilog2();
-        let hypercube: Vec<String> = boolean_hypercube::<F>(hypercube_size as usize);
+        EvaluationFormPolynomial {
+            representation: values.clone(),
+            num_vars: values.len().ilog2() as usize,
+        }
+    }
+
+    /// Creates a polynomial from evaluations of any length, padding with zeros up to the
+    /// next power of two.
+    pub fn new_padded(values: &[F]) -> Self {
+        let num_vars = values.len().next_power_of_two().ilog2() as usize;
+        Self::with_num_vars(values, num_vars).unwrap()
+    }
 
-        let evaluation = EvaluationFormPolynomial::default();
-        let mut data = evaluation.representation;
-        for i in 0..value {
-            data.push(values[i]);
+    /// Creates a polynomial over exactly `num_vars` variables, padding the evaluations with
+    /// zeros up to `2^num_vars`.
+    pub fn with_num_vars(values: &[F], num_vars: usize) -> Result<Self, PolynomialError> {
+        let len = 1 << num_vars;
+        if values.len() > len {
+            return Err(PolynomialError::TooManyEvaluations {
+                len: values.len(),
+                num_vars,
+            });
+        }
+        let mut representation = values.to_vec();
+        representation.resize(len, F::zero());
+        Ok(EvaluationFormPolynomial {
+            representation,
+            num_vars,
+        })
+    }
+
+    pub fn hypercube(&self) -> BooleanHypercube {
+        boolean_hypercube(self.num_vars)
+    }
+
+    /// Embeds the polynomial into a larger space of `num_vars` variables, where original
+    /// variable `i` becomes variable `positions[i]`. The result does not depend on the
+    /// remaining (dummy) variables.
+    pub fn embed(&self, num_vars: usize, positions: &[usize]) -> Self {
+        assert_eq!(
+            positions.len(),
+            self.num_vars,
+            "one position is needed per variable"
+        );
+        assert!(
+            positions.iter().all(|p| *p < num_vars),
+            "position out of range"
+        );
+        let mut seen = vec![false; num_vars];
+        for p in positions {
+            assert!(!seen[*p], "positions must be distinct");
+            seen[*p] = true;
         }
 
+        let representation = boolean_hypercube(num_vars)
+            .iter()
+            .map(|point| {
+                let index = positions
+                    .iter()
+                    .fold(0, |index, p| (index << 1) | point.bit(*p) as usize);
+                self.representation[index]
+            })
+            .collect();
+
         EvaluationFormPolynomial {
-            representation: data,
-            hypercube,
+            representation,
+            num_vars,
         }
     }
 
-    
-    pub fn partial_evaluate(&mut self, values: F, position: usize) -> Self {
-        let evaluation_form_vec = &self.representation;
-        let self_vec_len = evaluation_form_vec.len();
-        let mut poly: Vec<(String, F)> = Vec::new();
-        let mut rep = Vec::new();
+    /// Inserts `count` dummy variables starting at `position`, shifting the variables at and
+    /// after `position` to the right.
+    pub fn extend_variables(&self, position: usize, count: usize) -> Self {
+        assert!(position <= self.num_vars, "position out of range");
+        let positions: Vec<usize> = (0..self.num_vars)
+            .map(|i| if i < position { i } else { i + count })
+            .collect();
+        self.embed(self.num_vars + count, &positions)
+    }
+
+    /// Evaluates the polynomial at `point` as the inner product of its evaluations with the
+    /// `eq(point, x)` table.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "point must have one coordinate per variable"
+        );
+        let table = eq_table(point);
+        self.inner_product(&table)
+    }
 
-        for i in 0..self_vec_len {
-            let mut hypercube = self.hypercube[i].clone();
+    /// Evaluates several polynomials over the same variables at one point, building the
+    /// `eq` table only once.
+    pub fn evaluate_many(polys: &[Self], point: &[F]) -> Vec<F> {
+        let table = eq_table(point);
+        polys
+            .iter()
+            .map(|poly| {
+                assert_eq!(
+                    poly.representation.len(),
+                    table.len(),
+                    "point must have one coordinate per variable"
+                );
+                poly.inner_product(&table)
+            })
+            .collect()
+    }
 
-            hypercube.remove(position);
+    fn inner_product(&self, table: &[F]) -> F {
+        self.representation
+            .iter()
+            .zip(table)
+            .map(|(value, eq)| *value * eq)
+            .sum()
+    }
 
-            poly.push((hypercube, evaluation_form_vec[i]));
-        }
-        let mut merged_poly: Vec<(String, F)> = vec![];
-
-        for eval in poly {
-            if let Some(existing) = merged_poly.iter_mut().find(|e| e.0 == eval.0) {
-                existing.1 = existing.1 * (F::from(1u32) - values) + eval.1 * values;
-                rep.push(existing.1);
-            } else {
-                merged_poly.push(eval);
+    /// Fixes the variable at `position` to `values`, returning a polynomial in one fewer
+    /// variable. Each pair of evaluations that differ only in that variable is folded into
+    /// `(1 - r) * lo + r * hi` in a single pass.
+    pub fn partial_evaluate(&self, values: F, position: usize) -> Self {
+        let no_of_variables = self.num_vars;
+        assert!(position < no_of_variables, "position out of range");
+        let stride = 1 << (no_of_variables - 1 - position);
+
+        let mut eval_rep = Vec::with_capacity(self.representation.len() / 2);
+        for block in self.representation.chunks(2 * stride) {
+            let (lo, hi) = block.split_at(stride);
+            for (lo, hi) in lo.iter().zip(hi) {
+                eval_rep.push(*lo + values * (*hi - lo));
             }
         }
-        let (hypercube, eval_rep): (Vec<String>, Vec<F>) = merged_poly.into_iter().unzip();
 
         EvaluationFormPolynomial {
             representation: eval_rep,
-            hypercube,
+            num_vars: no_of_variables - 1,
         }
     }
-}
-#[derive(Debug, PartialEq, Clone)]
-pub struct MultilinearPolynomialSparse<F: PrimeField> {
-    polynomial: Vec<(F, Vec<F>)>,
-}
-impl<F: PrimeField> MultilinearPolynomialSparse<F> {
-    pub fn multilinear_monomial(coeff: F, variables: Vec<F>) -> (F, Vec<F>) {
-        let monomial: (F, Vec<F>) = (coeff, variables);
-        return monomial;
-    }
-
-    pub fn new(self) -> Self {
-        self
-    }
-
-    pub fn evaluation(&mut self, values: F, position: usize) -> Self {
-        let mut result = vec![];
-        for i in 0..self.polynomial.len() {
-            let coeff = self.polynomial[i].0.clone();
-            let expo = self.polynomial[i].1[position].clone();
-            let ans = coeff * values.pow(expo.into_bigint());
-
-            // Convert the 1 in the position evaluated to 0 after evaluation
-            self.polynomial[i].0 = ans;
-            self.polynomial[i].1[position] = F::from(0u64);
-            result = self.polynomial.clone();
-            if self.polynomial[i].1.iter().all(|&x| x == F::from(0u64)) {
-                let sum: F = result.iter().map(|(coeff, _)| *coeff).sum();
-                result = vec![(sum, vec![F::from(0u64); self.polynomial[i].1.len()])];
-            }
-        }
-        MultilinearPolynomialSparse { polynomial: result }
-    }
 
-    pub fn sum(&self, other: &Self) -> Self {
-        let mut polynomial = self.polynomial.clone();
-        let mut result = vec![];
+    /// In-place variant of `partial_evaluate` that reuses the existing allocation.
+    pub fn fix_variable_mut(&mut self, values: F, position: usize) {
+        let no_of_variables = self.num_vars;
+        assert!(position < no_of_variables, "position out of range");
+        let stride = 1 << (no_of_variables - 1 - position);
+        let half = self.representation.len() / 2;
 
-        for (coeff, vars) in &other.polynomial {
-            if let Some(existing) = polynomial.iter_mut().find(|(_, v)| v == vars) {
-                existing.0 += *coeff;
-            } else {
-                result.push((*coeff, vars.clone()));
-            }
+        // The output index never exceeds the index of the `lo` value it is computed from,
+        // so writing to the front of the vector never clobbers unread values.
+        for i in 0..half {
+            let lo = (i / stride) * 2 * stride + i % stride;
+            let hi = lo + stride;
+            let (lo, hi) = (self.representation[lo], self.representation[hi]);
+            self.representation[i] = lo + values * (hi - lo);
         }
-
-        MultilinearPolynomialSparse { polynomial: result }
+        self.representation.truncate(half);
+        self.num_vars -= 1;
     }
 }
 
@@ -136,6 +204,13 @@ impl<F: PrimeField> MultilinearPolynomialSparse<F> {
 mod tests {
     use super::*;
     use ark_bn254::Fq;
+    use std::ops::Add;
+    #[test]
+    fn test_default_is_empty() {
+        let poly = EvaluationFormPolynomial::<Fq>::default();
+        assert!(poly.representation.is_empty());
+        assert_eq!(poly.num_vars, 0);
+    }
     #[test]
     fn test_add_polynomials(){
          let values: Vec<Fq> = vec![Fq::from(0), Fq::from(2), Fq::from(0), Fq::from(5)];
@@ -156,13 +231,13 @@ mod tests {
     #[test]
     fn test_evaluation_form_partial_evaluation() {
         let values: Vec<Fq> = vec![Fq::from(0), Fq::from(2), Fq::from(0), Fq::from(5)];
-        let mut poly = EvaluationFormPolynomial::new(&values);
+        let poly = EvaluationFormPolynomial::new(&values);
         assert_eq!(
             poly.representation,
             vec![(Fq::from(0)), (Fq::from(2)), (Fq::from(0)), (Fq::from(5))]
         );
-        assert_eq!(poly.hypercube, vec!["00", "01", "10", "11"]);
-        let mut pol = poly.partial_evaluate(Fq::from(5), 0);
+        assert_eq!(poly.num_vars, 2);
+        let pol = poly.partial_evaluate(Fq::from(5), 0);
         assert_eq!(pol.representation, vec![(Fq::from(0)), (Fq::from(17))]);
 
         let result = pol.partial_evaluate(Fq::from(2), 0);
@@ -170,45 +245,113 @@ mod tests {
     }
 
     #[test]
-    fn test_sparse_partial_evaluation() {
-        let m_1 = MultilinearPolynomialSparse::multilinear_monomial(
-            Fq::from(3),
-            vec![Fq::from(0), Fq::from(1), Fq::from(1)],
+    fn test_partial_evaluate_any_position() {
+        // f(a, b, c) = a + 2b + 4c + 8abc
+        let values: Vec<Fq> = vec![0u64, 4, 2, 6, 1, 5, 3, 15]
+            .into_iter()
+            .map(Fq::from)
+            .collect();
+        let poly = EvaluationFormPolynomial::new(&values);
+
+        // b = 3: f(a, 3, c) = a + 6 + 4c + 24ac
+        let result = poly.partial_evaluate(Fq::from(3), 1);
+        assert_eq!(
+            result.representation,
+            vec![Fq::from(6), Fq::from(10), Fq::from(7), Fq::from(35)]
         );
-        let m_2 = MultilinearPolynomialSparse::multilinear_monomial(
-            Fq::from(4),
-            vec![Fq::from(1), Fq::from(1), Fq::from(0)],
+
+        // c = 3: f(a, b, 3) = a + 2b + 12 + 24ab
+        let result = poly.partial_evaluate(Fq::from(3), 2);
+        assert_eq!(
+            result.representation,
+            vec![Fq::from(12), Fq::from(14), Fq::from(13), Fq::from(39)]
         );
-        let m_3 = MultilinearPolynomialSparse::multilinear_monomial(
-            Fq::from(5),
-            vec![Fq::from(1), Fq::from(1), Fq::from(1)],
+
+        for position in 0..3 {
+            let mut in_place = poly.clone();
+            in_place.fix_variable_mut(Fq::from(7), position);
+            assert_eq!(in_place, poly.partial_evaluate(Fq::from(7), position));
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let values: Vec<Fq> = vec![Fq::from(0), Fq::from(2), Fq::from(0), Fq::from(5)];
+        let poly = EvaluationFormPolynomial::new(&values);
+        assert_eq!(poly.evaluate(&[Fq::from(5), Fq::from(2)]), Fq::from(34));
+
+        let other = EvaluationFormPolynomial::new(&vec![
+            Fq::from(1),
+            Fq::from(3),
+            Fq::from(2),
+            Fq::from(7),
+        ]);
+        let point = [Fq::from(4), Fq::from(9)];
+        let evals = EvaluationFormPolynomial::evaluate_many(&[poly.clone(), other.clone()], &point);
+        assert_eq!(evals, vec![poly.evaluate(&point), other.evaluate(&point)]);
+        assert_eq!(
+            evals[1],
+            other
+                .partial_evaluate(point[0], 0)
+                .partial_evaluate(point[1], 0)
+                .representation[0]
         );
-        let poly = vec![m_1, m_2, m_3];
-        let mut p =
-            MultilinearPolynomialSparse::new(MultilinearPolynomialSparse { polynomial: poly });
-        let mut ans = p.evaluation(Fq::from(5), 1);
+    }
+
+    #[test]
+    fn test_padding_constructors() {
+        let values = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+        let poly = EvaluationFormPolynomial::new_padded(&values);
+        assert_eq!(poly.num_vars, 2);
         assert_eq!(
-            ans.polynomial,
-            vec![
-                (Fq::from(15), vec![Fq::from(0), Fq::from(0), Fq::from(1)]),
-                (Fq::from(20), vec![Fq::from(1), Fq::from(0), Fq::from(0)]),
-                (Fq::from(25), vec![Fq::from(1), Fq::from(0), Fq::from(1)])
-            ]
+            poly.representation,
+            vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(0)]
         );
-        let mut ans1 = ans.evaluation(Fq::from(5), 0);
+
+        let single = EvaluationFormPolynomial::new_padded(&[Fq::from(7)]);
+        assert_eq!(single.num_vars, 0);
+        assert_eq!(single.evaluate(&[]), Fq::from(7));
+
+        let widened = EvaluationFormPolynomial::with_num_vars(&[Fq::from(7)], 1).unwrap();
+        assert_eq!(widened.representation, vec![Fq::from(7), Fq::from(0)]);
+
         assert_eq!(
-            ans1.polynomial,
-            vec![
-                (Fq::from(15), vec![Fq::from(0), Fq::from(0), Fq::from(1)]),
-                (Fq::from(100), vec![Fq::from(0), Fq::from(0), Fq::from(0)]),
-                (Fq::from(125), vec![Fq::from(0), Fq::from(0), Fq::from(1)])
-            ]
+            EvaluationFormPolynomial::with_num_vars(&values, 1),
+            Err(PolynomialError::TooManyEvaluations { len: 3, num_vars: 1 })
         );
-        let ans2 = ans1.evaluation(Fq::from(5), 2);
+    }
+
+    #[test]
+    fn test_extend_variables() {
+        // f(a, b) = a + 2b
+        let poly = EvaluationFormPolynomial::new(&vec![
+            Fq::from(0),
+            Fq::from(2),
+            Fq::from(1),
+            Fq::from(3),
+        ]);
+
+        // g(a, d, b) = f(a, b)
+        let extended = poly.extend_variables(1, 1);
+        assert_eq!(extended.num_vars, 3);
+        for a in 0..2u64 {
+            for d in 0..2u64 {
+                for b in 0..2u64 {
+                    let point = [Fq::from(a), Fq::from(d), Fq::from(b)];
+                    assert_eq!(
+                        extended.evaluate(&point),
+                        poly.evaluate(&[Fq::from(a), Fq::from(b)])
+                    );
+                }
+            }
+        }
+
+        // h(b, x, y, a) = f(a, b)
+        let embedded = poly.embed(4, &[3, 0]);
+        let point = [Fq::from(5), Fq::from(11), Fq::from(13), Fq::from(9)];
         assert_eq!(
-            ans2.polynomial,
-            vec![(Fq::from(800), vec![Fq::from(0), Fq::from(0), Fq::from(0)])]
-        )
+            embedded.evaluate(&point),
+            poly.evaluate(&[Fq::from(9), Fq::from(5)])
+        );
     }
-   
 }
@@ -1,3 +1,4 @@
+use crate::virtual_poly::VirtualPolynomial;
 use crate::EvaluationFormPolynomial;
 use ark_ff::PrimeField;
 use std::vec;
@@ -13,13 +14,11 @@ impl<F: PrimeField> SumPolynomial<F> {
         self.polyomials.push(poly);
     }
     pub fn add_polynomials(&mut self, polys: Vec<ProductPolynomial<F>>) {
-        for i in 0..polys.len() {
-            self.polyomials.push(polys[i].clone());
-        }
+        self.polyomials.extend(polys);
     }
     pub fn partial_evaluate(&self, value: F, position: usize) -> SumPolynomial<F> {
         let mut result = SumPolynomial::new(vec![]);
-        for mut poly in self.polyomials.clone() {  
+        for mut poly in self.polyomials.clone() {
             // Handle each ProductPolynomial's partial evaluation
             let evaluated = poly.partial_evaluate(value, position);
             result.add_polynomial(evaluated);
@@ -27,50 +26,12 @@ impl<F: PrimeField> SumPolynomial<F> {
 
         result
     }
+    /// Multiplies out every product and adds them up into a single one-factor product with
+    /// the same values on the hypercube, whatever the number of factors of each product.
     pub fn reduce(&self) -> SumPolynomial<F> {
-    // If there's only one polynomial, return a clone
-    if self.polyomials.len() == 1 {
-        return self.clone();
-    }
-
-    // Get the length of the first polynomial's representation to verify consistency
-    let expected_len = self.polyomials[0].polyomials[0].representation.len();
-
-    // Verify all polynomials have the same length
-    for product_poly in &self.polyomials {
-        if product_poly.polyomials[0].representation.len() != expected_len {
-            panic!("The number of monomials in all polynomials should be equal");
-        }
+        let reduced = VirtualPolynomial::from(self).to_evaluation_form();
+        SumPolynomial::new(vec![ProductPolynomial::new(vec![reduced])])
     }
-
-    // Initialize the result with default values
-    let mut total_result = SumPolynomial::new(vec![ProductPolynomial::new(vec![
-        EvaluationFormPolynomial::default(),
-    ])]);
-
-    // For each position in the representation
-    for i in 0..expected_len {
-        // Initialize sum in the prime field
-        let mut sum = F::zero();
-
-        // Sum up the evaluations from all polynomials at this position
-        for product_poly in &self.polyomials {
-            // Perform multiplication in the prime field
-            let eval = product_poly.polyomials[0].representation[i]
-                .mul(&product_poly.polyomials[1].representation[i]);
-            // Add in the prime field
-            sum = sum.add(&eval);
-        }
-
-        // Add the sum to the result
-        total_result.polyomials[0].polyomials[0]
-            .representation
-            .push(sum);
-    }
-
-    total_result
-}
-  
 }
 #[derive(Debug, Clone)]
 pub struct ProductPolynomial<F: PrimeField> {
@@ -84,82 +45,39 @@ impl<F: PrimeField> ProductPolynomial<F> {
         self.polyomials.push(poly);
     }
     pub fn add_polynomials(&mut self, polys: Vec<EvaluationFormPolynomial<F>>) {
-        for i in 0..polys.len() {
-            self.polyomials.push(polys[i].clone());
-        }
+        self.polyomials.extend(polys);
     }
     pub fn partial_evaluate(&mut self, value: F, position: usize) -> ProductPolynomial<F> {
         let mut result = ProductPolynomial::new(vec![]);
         for i in 0..self.polyomials.len() {
-            let mut poly = self.polyomials[i].clone();
-            let poly = poly.partial_evaluate(value, position);
+            let poly = self.polyomials[i].partial_evaluate(value, position);
             result.add_polynomial(poly);
         }
         result
     }
 
     pub fn evaluate(self, values: Vec<F>) -> F {
-        let mut result = F::from(1u32);
-        for i in 0..self.polyomials.len() {
-            let mut poly = self.polyomials[i].clone();
-            for j in 0..values.len() {
-                let value = values[j];
-                poly = poly.partial_evaluate(value, 0);
-            }
-            result = result * poly.representation[0];
-        }
-        //   for i in 0..poly.representation.len() {
-        //  result.push(poly.representation[i] * poly1.representation[i]);
-
-        // }
-        result
+        EvaluationFormPolynomial::evaluate_many(&self.polyomials, &values)
+            .into_iter()
+            .product()
     }
 
-    pub fn reduce(& self) -> ProductPolynomial<F> {
+    /// Multiplies the factors together into a single one, however many there are.
+    pub fn reduce(&self) -> ProductPolynomial<F> {
         if self.polyomials.len() == 1 {
             return self.clone();
         }
-        if self.polyomials.len() != 2 {
-            panic!("The number of polynomials should be 2");
-        } else if self.polyomials[0].representation.len() != self.polyomials[1].representation.len()
-        {
-            panic!("The number of monomials in the polynomials should be equal");
-        }
-        let mut result = EvaluationFormPolynomial::default();
-        for i in 0..self.polyomials[0].representation.len() {
-            let poly = self.polyomials[0].representation[i] * self.polyomials[1].representation[i];
-
-            result.representation.push(poly);
-        }
-        ProductPolynomial::new(vec![result])
+        ProductPolynomial::new(vec![VirtualPolynomial::from(self).to_evaluation_form()])
     }
     pub fn sum_poly(&self) -> EvaluationFormPolynomial<F> {
-     let mut result =  vec![];
-        for i in 0..self.polyomials[0].representation.len() {
-            let first_poly = self.polyomials[0].representation[i];
-            for j in 0..self.polyomials[1].representation.len() {
-                let second_poly = first_poly + self.polyomials[1].representation[j];
-                result.push(second_poly);
-            }
-        }
-          EvaluationFormPolynomial::new(&result)
+        self.polyomials[0].tensor_sum(&self.polyomials[1])
     }
-    pub fn mul_poly(&self) -> EvaluationFormPolynomial<F>{
-        let mut result =  vec![];
-
-        for i in 0..self.polyomials[0].representation.len() {
-            let first_poly = self.polyomials[0].representation[i];
-            for j in 0..self.polyomials[1].representation.len() {
-                let second_poly = first_poly * self.polyomials[1].representation[j];
-                result.push(second_poly);
-            }
-        }
-        EvaluationFormPolynomial::new(&result)
+    pub fn mul_poly(&self) -> EvaluationFormPolynomial<F> {
+        self.polyomials[0].tensor_product(&self.polyomials[1])
     }
 
     pub fn degree(&self) -> usize {
-        let degree = self.polyomials.len();
-        degree
+        self.polyomials.len()
     }
 }
 #[cfg(test)]
@@ -172,13 +90,13 @@ mod tests {
         let poly = EvaluationFormPolynomial::new(&values);
         let values1: Vec<Fq> = vec![Fq::from(0), Fq::from(0), Fq::from(0), Fq::from(4)];
         let poly1 = EvaluationFormPolynomial::new(&values1);
-        let mut product = ProductPolynomial::new(vec![poly, poly1]);
+        let product = ProductPolynomial::new(vec![poly, poly1]);
 
         let values2: Vec<Fq> = vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)];
         let poly2 = EvaluationFormPolynomial::new(&values2);
         let values3: Vec<Fq> = vec![Fq::from(0), Fq::from(0), Fq::from(0), Fq::from(4)];
         let poly3 = EvaluationFormPolynomial::new(&values3);
-        let mut product1 = ProductPolynomial::new(vec![poly2, poly3]);
+        let product1 = ProductPolynomial::new(vec![poly2, poly3]);
 
         let sum = SumPolynomial::new(vec![product, product1]);
 
@@ -188,6 +106,33 @@ mod tests {
             vec![Fq::from(0), Fq::from(0), Fq::from(0), Fq::from(40)]
         );
     }
+    #[test]
+    fn test_reduce_many_factors() {
+        let a = EvaluationFormPolynomial::new(&vec![
+            Fq::from(1),
+            Fq::from(2),
+            Fq::from(3),
+            Fq::from(4),
+        ]);
+        let b = EvaluationFormPolynomial::new(&vec![
+            Fq::from(5),
+            Fq::from(6),
+            Fq::from(7),
+            Fq::from(8),
+        ]);
+        let product = ProductPolynomial::new(vec![a.clone(), b.clone(), a.clone()]);
+        assert_eq!(
+            product.reduce().polyomials[0].representation,
+            vec![Fq::from(5), Fq::from(24), Fq::from(63), Fq::from(128)]
+        );
+
+        let sum = SumPolynomial::new(vec![product, ProductPolynomial::new(vec![b])]);
+        assert_eq!(
+            sum.reduce().polyomials[0].polyomials[0].representation,
+            vec![Fq::from(10), Fq::from(30), Fq::from(70), Fq::from(136)]
+        );
+    }
+
     #[test]
     fn test_sumpolynomial_partial_evaluate() {
         let values: Vec<Fq> = vec![Fq::from(0), Fq::from(3), Fq::from(2), Fq::from(5)];
@@ -251,8 +196,9 @@ mod tests {
         let values1: Vec<Fq> = vec![Fq::from(0), Fq::from(0), Fq::from(0), Fq::from(4)];
         let poly1 = EvaluationFormPolynomial::new(&values1);
         let product = ProductPolynomial::new(vec![poly, poly1]);
+        // (2a + 3b) * 4ab at (5, 2) = 16 * 40
         let result = product.evaluate(vec![Fq::from(5), Fq::from(2)]);
-        assert_eq!(result, Fq::from(56));
+        assert_eq!(result, Fq::from(640));
     }
     #[test]
     fn test_product_poly_degree() {
@@ -273,7 +219,7 @@ mod tests {
         let poly1 = EvaluationFormPolynomial::new(&values1);
         let product = ProductPolynomial::new(vec![poly, poly1]);
         let result = product.sum_poly();
-     
+
         assert_eq!(
             result.representation,
             vec![
@@ -289,8 +235,8 @@ mod tests {
         );
     }
     #[test]
-    fn test_mul_poly(){
-           let values: Vec<Fq> = vec![Fq::from(3), Fq::from(3), Fq::from(3), Fq::from(5)];
+    fn test_mul_poly() {
+        let values: Vec<Fq> = vec![Fq::from(3), Fq::from(3), Fq::from(3), Fq::from(5)];
         let poly = EvaluationFormPolynomial::new(&values);
         let values1: Vec<Fq> = vec![Fq::from(6), Fq::from(8)];
         let poly1 = EvaluationFormPolynomial::new(&values1);
@@ -309,9 +255,6 @@ mod tests {
                 Fq::from(40)
             ]
         );
-        
-        
-        
     }
     // #[test]
     // fn test_reduce_add() {
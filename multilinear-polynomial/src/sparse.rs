@@ -0,0 +1,352 @@
+use crate::error::PolynomialError;
+use ark_ff::PrimeField;
+use std::ops::{Add, Neg, Sub};
+
+/// A multilinear polynomial in coefficient (monomial) form.
+///
+/// Each term is a coefficient together with a vector of `num_vars` exponents, each `0` or `1`,
+/// so `(3, [0, 1, 1])` is the monomial `3 * x_1 * x_2`. Terms are kept normalized: exponent
+/// vectors are unique, coefficients are non-zero and terms are sorted by exponent vector.
+#[derive(Debug, PartialEq, Clone)]
+pub struct MultilinearPolynomialSparse<F: PrimeField> {
+    pub num_vars: usize,
+    pub polynomial: Vec<(F, Vec<F>)>,
+}
+
+impl<F: PrimeField> MultilinearPolynomialSparse<F> {
+    pub fn multilinear_monomial(coeff: F, variables: Vec<F>) -> (F, Vec<F>) {
+        (coeff, variables)
+    }
+
+    /// Creates a polynomial over `num_vars` variables from a list of terms, combining terms
+    /// with the same monomial and dropping zero coefficients.
+    pub fn new(num_vars: usize, polynomial: Vec<(F, Vec<F>)>) -> Self {
+        for (_, vars) in &polynomial {
+            assert_eq!(
+                vars.len(),
+                num_vars,
+                "every monomial must have one exponent per variable"
+            );
+            assert!(
+                vars.iter().all(|v| v.is_zero() || v.is_one()),
+                "exponents of a multilinear monomial must be 0 or 1"
+            );
+        }
+        let mut result = MultilinearPolynomialSparse {
+            num_vars,
+            polynomial,
+        };
+        result.normalize();
+        result
+    }
+
+    /// The zero polynomial over `num_vars` variables.
+    pub fn zero(num_vars: usize) -> Self {
+        MultilinearPolynomialSparse {
+            num_vars,
+            polynomial: vec![],
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.polynomial.is_empty()
+    }
+
+    /// Sorts the terms, merges terms with the same monomial and removes zero coefficients.
+    pub fn normalize(&mut self) {
+        self.polynomial.sort_by(|a, b| a.1.cmp(&b.1));
+        let mut merged: Vec<(F, Vec<F>)> = Vec::with_capacity(self.polynomial.len());
+        for (coeff, vars) in self.polynomial.drain(..) {
+            match merged.last_mut() {
+                Some(last) if last.1 == vars => last.0 += coeff,
+                _ => merged.push((coeff, vars)),
+            }
+        }
+        merged.retain(|(coeff, _)| !coeff.is_zero());
+        self.polynomial = merged;
+    }
+
+    /// Evaluates the polynomial at `point`.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "point must have one coordinate per variable"
+        );
+        self.polynomial
+            .iter()
+            .map(|(coeff, vars)| {
+                vars.iter()
+                    .zip(point)
+                    .filter(|(v, _)| v.is_one())
+                    .fold(*coeff, |acc, (_, p)| acc * p)
+            })
+            .sum()
+    }
+
+    /// Fixes the variable at `position` to `value`, returning a polynomial in one fewer
+    /// variable.
+    pub fn partial_evaluate(&self, value: F, position: usize) -> Self {
+        assert!(position < self.num_vars, "position out of range");
+        let polynomial = self
+            .polynomial
+            .iter()
+            .map(|(coeff, vars)| {
+                let mut vars = vars.clone();
+                let exponent = vars.remove(position);
+                let coeff = if exponent.is_one() {
+                    *coeff * value
+                } else {
+                    *coeff
+                };
+                (coeff, vars)
+            })
+            .collect();
+
+        let mut result = MultilinearPolynomialSparse {
+            num_vars: self.num_vars - 1,
+            polynomial,
+        };
+        result.normalize();
+        result
+    }
+
+    fn check_same_variables(&self, other: &Self) -> Result<(), PolynomialError> {
+        if self.num_vars != other.num_vars {
+            return Err(PolynomialError::VariableCountMismatch {
+                left: self.num_vars,
+                right: other.num_vars,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds two polynomials, failing if they are over a different number of variables.
+    pub fn checked_add(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_same_variables(other)?;
+        let polynomial = self
+            .polynomial
+            .iter()
+            .chain(&other.polynomial)
+            .cloned()
+            .collect();
+        Ok(MultilinearPolynomialSparse::new(self.num_vars, polynomial))
+    }
+
+    /// Subtracts two polynomials, failing if they are over a different number of variables.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.checked_add(&-other.clone())
+    }
+
+    /// Multiplies two polynomials pointwise on the boolean hypercube, failing if they are over
+    /// a different number of variables.
+    ///
+    /// Every `x^2` is reduced to `x`, so the result is the multilinear extension of the
+    /// product's values on the hypercube, not the product itself: it differs from `p(x) * q(x)`
+    /// at other points, e.g. `(a + b) * (a - b)` becomes `a - b`.
+    pub fn mul_on_hypercube(&self, other: &Self) -> Result<Self, PolynomialError> {
+        self.check_same_variables(other)?;
+        let mut polynomial = Vec::with_capacity(self.polynomial.len() * other.polynomial.len());
+        for (coeff_a, vars_a) in &self.polynomial {
+            for (coeff_b, vars_b) in &other.polynomial {
+                let vars = vars_a
+                    .iter()
+                    .zip(vars_b)
+                    .map(|(a, b)| {
+                        if a.is_one() || b.is_one() {
+                            F::one()
+                        } else {
+                            F::zero()
+                        }
+                    })
+                    .collect();
+                polynomial.push((*coeff_a * coeff_b, vars));
+            }
+        }
+        Ok(MultilinearPolynomialSparse::new(self.num_vars, polynomial))
+    }
+}
+
+impl<F: PrimeField> Add for &MultilinearPolynomialSparse<F> {
+    type Output = MultilinearPolynomialSparse<F>;
+
+    fn add(self, other: Self) -> MultilinearPolynomialSparse<F> {
+        self.checked_add(other).unwrap()
+    }
+}
+
+impl<F: PrimeField> Add for MultilinearPolynomialSparse<F> {
+    type Output = MultilinearPolynomialSparse<F>;
+
+    fn add(self, other: Self) -> MultilinearPolynomialSparse<F> {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Sub for &MultilinearPolynomialSparse<F> {
+    type Output = MultilinearPolynomialSparse<F>;
+
+    fn sub(self, other: Self) -> MultilinearPolynomialSparse<F> {
+        self.checked_sub(other).unwrap()
+    }
+}
+
+impl<F: PrimeField> Sub for MultilinearPolynomialSparse<F> {
+    type Output = MultilinearPolynomialSparse<F>;
+
+    fn sub(self, other: Self) -> MultilinearPolynomialSparse<F> {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField> Neg for MultilinearPolynomialSparse<F> {
+    type Output = MultilinearPolynomialSparse<F>;
+
+    fn neg(mut self) -> MultilinearPolynomialSparse<F> {
+        self.polynomial
+            .iter_mut()
+            .for_each(|(coeff, _)| *coeff = -*coeff);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+    use ark_ff::UniformRand;
+    use ark_std::test_rng;
+
+    fn vars(bits: &[u64]) -> Vec<Fq> {
+        bits.iter().map(|b| Fq::from(*b)).collect()
+    }
+
+    fn random_sparse(
+        num_vars: usize,
+        rng: &mut impl ark_std::rand::Rng,
+    ) -> MultilinearPolynomialSparse<Fq> {
+        let terms = (0..rng.gen_range(0..6))
+            .map(|_| {
+                let exponents = (0..num_vars)
+                    .map(|_| Fq::from(rng.gen_range(0..2u64)))
+                    .collect();
+                (Fq::rand(rng), exponents)
+            })
+            .collect();
+        MultilinearPolynomialSparse::new(num_vars, terms)
+    }
+
+    #[test]
+    fn test_sparse_partial_evaluation() {
+        let m_1 = MultilinearPolynomialSparse::multilinear_monomial(Fq::from(3), vars(&[0, 1, 1]));
+        let m_2 = MultilinearPolynomialSparse::multilinear_monomial(Fq::from(4), vars(&[1, 1, 0]));
+        let m_3 = MultilinearPolynomialSparse::multilinear_monomial(Fq::from(5), vars(&[1, 1, 1]));
+        let p = MultilinearPolynomialSparse::new(3, vec![m_1, m_2, m_3]);
+
+        // b = 5: 15c + 20a + 25ac
+        let ans = p.partial_evaluate(Fq::from(5), 1);
+        assert_eq!(
+            ans.polynomial,
+            vec![
+                (Fq::from(15), vars(&[0, 1])),
+                (Fq::from(20), vars(&[1, 0])),
+                (Fq::from(25), vars(&[1, 1])),
+            ]
+        );
+        // a = 5: 100 + 140c
+        let ans1 = ans.partial_evaluate(Fq::from(5), 0);
+        assert_eq!(
+            ans1.polynomial,
+            vec![(Fq::from(100), vars(&[0])), (Fq::from(140), vars(&[1]))]
+        );
+        let ans2 = ans1.partial_evaluate(Fq::from(5), 0);
+        assert_eq!(ans2.polynomial, vec![(Fq::from(800), vec![])]);
+        assert_eq!(
+            p.evaluate(&[Fq::from(5), Fq::from(5), Fq::from(5)]),
+            Fq::from(800)
+        );
+    }
+
+    #[test]
+    fn test_normalization() {
+        let p = MultilinearPolynomialSparse::new(
+            2,
+            vec![
+                (Fq::from(3), vars(&[1, 0])),
+                (Fq::from(2), vars(&[0, 1])),
+                (Fq::from(4), vars(&[1, 0])),
+                (Fq::from(2), vars(&[1, 1])),
+                (-Fq::from(2), vars(&[1, 1])),
+            ],
+        );
+        assert_eq!(
+            p.polynomial,
+            vec![(Fq::from(2), vars(&[0, 1])), (Fq::from(7), vars(&[1, 0]))]
+        );
+    }
+
+    #[test]
+    fn test_sparse_arithmetic() {
+        // (a + b) + (a - b) = 2a, (a + b) * (a - b) = a - b on the hypercube
+        let p = MultilinearPolynomialSparse::new(
+            2,
+            vec![(Fq::from(1), vars(&[1, 0])), (Fq::from(1), vars(&[0, 1]))],
+        );
+        let q = MultilinearPolynomialSparse::new(
+            2,
+            vec![(Fq::from(1), vars(&[1, 0])), (-Fq::from(1), vars(&[0, 1]))],
+        );
+        assert_eq!((&p + &q).polynomial, vec![(Fq::from(2), vars(&[1, 0]))]);
+        assert_eq!((&p - &q).polynomial, vec![(Fq::from(2), vars(&[0, 1]))]);
+        let product = p.mul_on_hypercube(&q).unwrap();
+        assert_eq!(
+            product.polynomial,
+            vec![(-Fq::from(1), vars(&[0, 1])), (Fq::from(1), vars(&[1, 0]))]
+        );
+        // Off the hypercube it is not the product: at (2, 0), (a + b)(a - b) = 4 but a - b = 2
+        let point = [Fq::from(2), Fq::from(0)];
+        assert_ne!(
+            product.evaluate(&point),
+            p.evaluate(&point) * q.evaluate(&point)
+        );
+        assert!((&p - &p).is_zero());
+        assert_eq!(
+            p.checked_add(&MultilinearPolynomialSparse::zero(3)),
+            Err(PolynomialError::VariableCountMismatch { left: 2, right: 3 })
+        );
+    }
+
+    #[test]
+    fn test_sparse_matches_evaluation_form() {
+        let mut rng = test_rng();
+        let num_vars = 4;
+        for _ in 0..20 {
+            let p = random_sparse(num_vars, &mut rng);
+            let q = random_sparse(num_vars, &mut rng);
+            let p_eval = p.to_evaluation_form();
+            let q_eval = q.to_evaluation_form();
+
+            assert_eq!((&p + &q).to_evaluation_form(), &p_eval + &q_eval);
+            assert_eq!((&p - &q).to_evaluation_form(), &p_eval - &q_eval);
+            assert_eq!(
+                p.mul_on_hypercube(&q).unwrap().to_evaluation_form(),
+                &p_eval * &q_eval
+            );
+
+            let point: Vec<Fq> = (0..num_vars).map(|_| Fq::rand(&mut rng)).collect();
+            assert_eq!(p.evaluate(&point), p_eval.evaluate(&point));
+
+            let r = Fq::rand(&mut rng);
+            for position in 0..num_vars {
+                assert_eq!(
+                    p.partial_evaluate(r, position).to_evaluation_form(),
+                    p_eval.partial_evaluate(r, position)
+                );
+            }
+            assert_eq!(
+                MultilinearPolynomialSparse::from_evaluation_form(&p_eval),
+                p
+            );
+        }
+    }
+}
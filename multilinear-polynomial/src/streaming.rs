@@ -0,0 +1,394 @@
+use crate::EvaluationFormPolynomial;
+use ark_ff::{BigInteger, PrimeField};
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// A source of hypercube evaluations that can be read in chunks, so the full evaluation
+/// vector never has to be held in memory.
+pub trait EvaluationSource<F: PrimeField> {
+    fn num_vars(&self) -> usize;
+
+    /// Reads the evaluations at indices `start..start + buf.len()` into `buf`.
+    fn read(&mut self, start: usize, buf: &mut [F]) -> io::Result<()>;
+}
+
+/// Evaluations stored in a file as fixed-width little-endian field elements, in hypercube
+/// order.
+#[derive(Debug)]
+pub struct FileSource<F: PrimeField> {
+    file: File,
+    num_vars: usize,
+    bytes: Vec<u8>,
+    _field: PhantomData<F>,
+}
+
+fn element_size<F: PrimeField>() -> usize {
+    F::zero().into_bigint().to_bytes_le().len()
+}
+
+impl<F: PrimeField> FileSource<F> {
+    /// Writes `evaluations` to `path` and opens the file as a source over `num_vars`
+    /// variables. Exactly `2^num_vars` evaluations must be given; otherwise the partly written
+    /// file is removed.
+    pub fn create(
+        path: impl AsRef<Path>,
+        num_vars: usize,
+        evaluations: impl IntoIterator<Item = F>,
+    ) -> io::Result<Self> {
+        if let Err(error) = Self::write_evaluations(&path, num_vars, evaluations) {
+            let _ = fs::remove_file(&path);
+            return Err(error);
+        }
+        Self::open(path)
+    }
+
+    fn write_evaluations(
+        path: impl AsRef<Path>,
+        num_vars: usize,
+        evaluations: impl IntoIterator<Item = F>,
+    ) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&path)?);
+        let mut count = 0usize;
+        for evaluation in evaluations {
+            writer.write_all(&evaluation.into_bigint().to_bytes_le())?;
+            count += 1;
+        }
+        writer.flush()?;
+        if count != 1 << num_vars {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "number of evaluations must be 2^num_vars",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Opens an existing evaluation file; the number of variables is derived from its size.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let size = file.metadata()?.len() as usize;
+        if !size.is_multiple_of(element_size::<F>()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "file size must be a multiple of the field element size",
+            ));
+        }
+        let len = size / element_size::<F>();
+        if !len.is_power_of_two() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "number of evaluations must be a power of 2",
+            ));
+        }
+        Ok(FileSource {
+            file,
+            num_vars: len.ilog2() as usize,
+            bytes: vec![],
+            _field: PhantomData,
+        })
+    }
+}
+
+impl<F: PrimeField> EvaluationSource<F> for FileSource<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn read(&mut self, start: usize, buf: &mut [F]) -> io::Result<()> {
+        let size = element_size::<F>();
+        self.file.seek(SeekFrom::Start((start * size) as u64))?;
+        self.bytes.resize(buf.len() * size, 0);
+        self.file.read_exact(&mut self.bytes)?;
+        for (value, bytes) in buf.iter_mut().zip(self.bytes.chunks(size)) {
+            let mut bigint = F::BigInt::default();
+            for (limb, limb_bytes) in bigint.as_mut().iter_mut().zip(bytes.chunks_exact(8)) {
+                *limb = u64::from_le_bytes(limb_bytes.try_into().unwrap());
+            }
+            *value = F::from_bigint(bigint).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "evaluation is not a canonical field element",
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Evaluations produced on demand by a function of the hypercube index, for data that is
+/// generated or derived rather than stored.
+pub struct FnSource<F, G> {
+    num_vars: usize,
+    generator: G,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, G: Fn(usize) -> F> FnSource<F, G> {
+    pub fn new(num_vars: usize, generator: G) -> Self {
+        FnSource {
+            num_vars,
+            generator,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F: PrimeField, G: Fn(usize) -> F> EvaluationSource<F> for FnSource<F, G> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn read(&mut self, start: usize, buf: &mut [F]) -> io::Result<()> {
+        for (i, value) in buf.iter_mut().enumerate() {
+            *value = (self.generator)(start + i);
+        }
+        Ok(())
+    }
+}
+
+/// A multilinear polynomial in evaluation form whose evaluations are streamed from an
+/// `EvaluationSource` in fixed-size chunks.
+pub struct StreamingPolynomial<F: PrimeField, S: EvaluationSource<F>> {
+    pub source: S,
+    pub chunk_size: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, S: EvaluationSource<F>> StreamingPolynomial<F, S> {
+    pub fn new(source: S) -> Self {
+        Self::with_chunk_size(source, 1 << 16)
+    }
+
+    pub fn with_chunk_size(source: S, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        StreamingPolynomial {
+            source,
+            chunk_size,
+            _field: PhantomData,
+        }
+    }
+
+    pub fn num_vars(&self) -> usize {
+        self.source.num_vars()
+    }
+
+    /// Streams matching chunks of the `x_0 = 0` and `x_0 = 1` halves of the hypercube.
+    fn for_each_half_chunk(&mut self, mut f: impl FnMut(&[F], &[F])) -> io::Result<()> {
+        let num_vars = self.num_vars();
+        assert!(num_vars > 0, "polynomial has no variables to fold");
+        let half = 1 << (num_vars - 1);
+        let chunk_size = self.chunk_size.min(half);
+        let mut lo = vec![F::zero(); chunk_size];
+        let mut hi = vec![F::zero(); chunk_size];
+
+        for start in (0..half).step_by(chunk_size) {
+            let len = chunk_size.min(half - start);
+            self.source.read(start, &mut lo[..len])?;
+            self.source.read(half + start, &mut hi[..len])?;
+            f(&lo[..len], &hi[..len]);
+        }
+        Ok(())
+    }
+
+    /// Computes, in a single pass, the sum over the hypercube together with the first
+    /// sumcheck round message `[Σ f(0, x), Σ f(1, x)]`.
+    pub fn sum_with_round(&mut self) -> io::Result<(F, Vec<F>)> {
+        if self.num_vars() == 0 {
+            let mut value = [F::zero()];
+            self.source.read(0, &mut value)?;
+            return Ok((value[0], vec![]));
+        }
+        let (mut lo_sum, mut hi_sum) = (F::zero(), F::zero());
+        self.for_each_half_chunk(|lo, hi| {
+            lo_sum += lo.iter().sum::<F>();
+            hi_sum += hi.iter().sum::<F>();
+        })?;
+        Ok((lo_sum + hi_sum, vec![lo_sum, hi_sum]))
+    }
+
+    /// Sum of the polynomial over the boolean hypercube, in a single pass.
+    pub fn sum(&mut self) -> io::Result<F> {
+        Ok(self.sum_with_round()?.0)
+    }
+
+    /// Fixes the first variable to `r` in a single pass, returning the folded polynomial in
+    /// memory. Use once `2^(n-1)` evaluations fit in RAM.
+    pub fn fold_first_variable(&mut self, r: F) -> io::Result<EvaluationFormPolynomial<F>> {
+        let mut representation = Vec::with_capacity(1 << self.num_vars().saturating_sub(1));
+        self.for_each_half_chunk(|lo, hi| {
+            representation.extend(lo.iter().zip(hi).map(|(lo, hi)| *lo + r * (*hi - lo)));
+        })?;
+        Ok(EvaluationFormPolynomial::new(&representation))
+    }
+
+    /// Fixes the first variable to `r` in a single pass, writing the folded polynomial to
+    /// `path` so that it stays out of core. If the pass fails, the partly written file is
+    /// removed.
+    pub fn fold_first_variable_to_file(
+        &mut self,
+        r: F,
+        path: impl AsRef<Path>,
+    ) -> io::Result<StreamingPolynomial<F, FileSource<F>>> {
+        if let Err(error) = self.write_folded(r, &path) {
+            let _ = fs::remove_file(&path);
+            return Err(error);
+        }
+
+        let source = FileSource::open(path)?;
+        Ok(StreamingPolynomial::with_chunk_size(
+            source,
+            self.chunk_size,
+        ))
+    }
+
+    fn write_folded(&mut self, r: F, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(&path)?);
+        let mut result = Ok(());
+        self.for_each_half_chunk(|lo, hi| {
+            for (lo, hi) in lo.iter().zip(hi) {
+                if result.is_ok() {
+                    result = writer.write_all(&(*lo + r * (*hi - lo)).into_bigint().to_bytes_le());
+                }
+            }
+        })?;
+        result?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn values(num_vars: usize) -> Vec<Fq> {
+        (0..1u64 << num_vars).map(|i| Fq::from(i * 7 + 1)).collect()
+    }
+
+    #[test]
+    fn test_streaming_from_function() {
+        let num_vars = 6;
+        let poly = EvaluationFormPolynomial::new(&values(num_vars));
+        let mut stream = StreamingPolynomial::with_chunk_size(
+            FnSource::new(num_vars, |i| Fq::from(i as u64 * 7 + 1)),
+            5,
+        );
+
+        let (sum, round) = stream.sum_with_round().unwrap();
+        assert_eq!(sum, poly.representation.iter().sum::<Fq>());
+        assert_eq!(
+            round[0],
+            poly.partial_evaluate(Fq::from(0), 0)
+                .representation
+                .iter()
+                .sum::<Fq>()
+        );
+        assert_eq!(round[0] + round[1], sum);
+
+        let r = Fq::from(11);
+        assert_eq!(
+            stream.fold_first_variable(r).unwrap(),
+            poly.partial_evaluate(r, 0)
+        );
+    }
+
+    #[test]
+    fn test_streaming_from_file() {
+        let num_vars = 5;
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("streaming-poly-{}.bin", std::process::id()));
+        let folded_path = dir.join(format!("streaming-poly-folded-{}.bin", std::process::id()));
+
+        let poly = EvaluationFormPolynomial::new(&values(num_vars));
+        let source = FileSource::create(&path, num_vars, values(num_vars)).unwrap();
+        let mut stream = StreamingPolynomial::with_chunk_size(source, 4);
+        assert_eq!(stream.num_vars(), num_vars);
+        assert_eq!(
+            stream.sum().unwrap(),
+            poly.representation.iter().sum::<Fq>()
+        );
+
+        let r = Fq::from(3);
+        let mut folded = stream.fold_first_variable_to_file(r, &folded_path).unwrap();
+        let expected = poly.partial_evaluate(r, 0);
+        assert_eq!(folded.num_vars(), num_vars - 1);
+        assert_eq!(
+            folded.sum().unwrap(),
+            expected.representation.iter().sum::<Fq>()
+        );
+        assert_eq!(
+            folded.fold_first_variable(r).unwrap(),
+            expected.partial_evaluate(r, 0)
+        );
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(folded_path).unwrap();
+    }
+
+    #[test]
+    fn test_file_source_rejects_bad_files() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("streaming-poly-bad-{}.bin", std::process::id()));
+        let size = element_size::<Fq>();
+
+        // A wrong count fails without leaving the partly written file behind
+        let error = FileSource::create(&path, 3, values(2)).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+
+        // A partial trailing element is not silently dropped
+        std::fs::write(&path, vec![0u8; 4 * size + 7]).unwrap();
+        let error = FileSource::<Fq>::open(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        // Bytes at or above the modulus are corrupt rather than reduced
+        let mut bytes = vec![0u8; 2 * size];
+        bytes[size..].fill(0xff);
+        std::fs::write(&path, bytes).unwrap();
+        let mut source = FileSource::<Fq>::open(&path).unwrap();
+        let mut buf = [Fq::from(0); 2];
+        let error = source.read(0, &mut buf).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    /// A source whose reads fail from index `fail_from` on.
+    struct FailingSource {
+        num_vars: usize,
+        fail_from: usize,
+    }
+
+    impl EvaluationSource<Fq> for FailingSource {
+        fn num_vars(&self) -> usize {
+            self.num_vars
+        }
+
+        fn read(&mut self, start: usize, buf: &mut [Fq]) -> io::Result<()> {
+            if start + buf.len() > self.fail_from {
+                return Err(io::Error::other("read failed"));
+            }
+            buf.fill(Fq::from(1));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_failed_fold_removes_output() {
+        let path = std::env::temp_dir().join(format!(
+            "streaming-poly-failed-fold-{}.bin",
+            std::process::id()
+        ));
+        let source = FailingSource {
+            num_vars: 4,
+            fail_from: 12,
+        };
+        let mut stream = StreamingPolynomial::with_chunk_size(source, 2);
+        assert!(stream
+            .fold_first_variable_to_file(Fq::from(3), &path)
+            .is_err());
+        assert!(!path.exists());
+    }
+}
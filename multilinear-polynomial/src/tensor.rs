@@ -0,0 +1,81 @@
+use crate::EvaluationFormPolynomial;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> EvaluationFormPolynomial<F> {
+    fn tensor_with(&self, other: &Self, op: impl Fn(F, F) -> F) -> Self {
+        let mut representation =
+            Vec::with_capacity(self.representation.len() * other.representation.len());
+        for a in &self.representation {
+            for b in &other.representation {
+                representation.push(op(*a, *b));
+            }
+        }
+        EvaluationFormPolynomial {
+            representation,
+            num_vars: self.num_vars + other.num_vars,
+        }
+    }
+
+    /// Tensor sum over disjoint variables: `h(x, y) = f(x) + g(y)`, with the variables of
+    /// `self` first.
+    pub fn tensor_sum(&self, other: &Self) -> Self {
+        self.tensor_with(other, |a, b| a + b)
+    }
+
+    /// Tensor product over disjoint variables: `h(x, y) = f(x) * g(y)`, with the variables
+    /// of `self` first.
+    pub fn tensor_product(&self, other: &Self) -> Self {
+        self.tensor_with(other, |a, b| a * b)
+    }
+
+    /// Concatenates two polynomials over the same variables using a new leading selector
+    /// variable: `h(s, x) = (1 - s) * f(x) + s * g(x)`.
+    pub fn concat(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.num_vars, other.num_vars,
+            "polynomials must have the same number of variables"
+        );
+        EvaluationFormPolynomial::merge_variables(&[self.clone(), other.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(values: &[u64]) -> EvaluationFormPolynomial<Fq> {
+        EvaluationFormPolynomial::new(&values.iter().map(|v| Fq::from(*v)).collect())
+    }
+
+    #[test]
+    fn test_tensor_sum_and_product() {
+        let f = poly(&[3, 3, 3, 5]);
+        let g = poly(&[6, 8]);
+        let x = [Fq::from(4), Fq::from(9)];
+        let y = [Fq::from(7)];
+        let xy = [x[0], x[1], y[0]];
+
+        let sum = f.tensor_sum(&g);
+        assert_eq!(sum.num_vars, 3);
+        assert_eq!(
+            sum.representation,
+            poly(&[9, 11, 9, 11, 9, 11, 11, 13]).representation
+        );
+        assert_eq!(sum.evaluate(&xy), f.evaluate(&x) + g.evaluate(&y));
+
+        let product = f.tensor_product(&g);
+        assert_eq!(product.evaluate(&xy), f.evaluate(&x) * g.evaluate(&y));
+    }
+
+    #[test]
+    fn test_concat() {
+        let f = poly(&[1, 2]);
+        let g = poly(&[3, 4]);
+        let h = f.concat(&g);
+        assert_eq!(h, poly(&[1, 2, 3, 4]));
+        let x = [Fq::from(5)];
+        assert_eq!(h.evaluate(&[Fq::from(0), x[0]]), f.evaluate(&x));
+        assert_eq!(h.evaluate(&[Fq::from(1), x[0]]), g.evaluate(&x));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::eq_poly::eq_table;
+use crate::EvaluationFormPolynomial;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> EvaluationFormPolynomial<F> {
+    /// Reorders the variables so that variable `i` of the result is variable `permutation[i]`
+    /// of this polynomial.
+    pub fn permute_variables(&self, permutation: &[usize]) -> Self {
+        assert_eq!(
+            permutation.len(),
+            self.num_vars,
+            "permutation must cover every variable"
+        );
+        let mut positions = vec![usize::MAX; self.num_vars];
+        for (new_position, old_position) in permutation.iter().enumerate() {
+            assert!(
+                *old_position < self.num_vars && positions[*old_position] == usize::MAX,
+                "permutation must be a bijection on the variables"
+            );
+            positions[*old_position] = new_position;
+        }
+        self.embed(self.num_vars, &positions)
+    }
+
+    /// Moves the first `at` variables after the remaining ones, turning `f(x, y)` into
+    /// `g(y, x) = f(x, y)`.
+    pub fn transpose(&self, at: usize) -> Self {
+        assert!(at <= self.num_vars, "split point out of range");
+        let permutation: Vec<usize> = (at..self.num_vars).chain(0..at).collect();
+        self.permute_variables(&permutation)
+    }
+
+    /// Fixes the contiguous block of variables starting at `position` to `values` in a single
+    /// pass, e.g. the `a` block (`position = 0`) or the `c` block (at the end) of a wiring
+    /// predicate `add_i(a, b, c)`.
+    pub fn fix_variables(&self, values: &[F], position: usize) -> Self {
+        assert!(
+            position + values.len() <= self.num_vars,
+            "block of variables out of range"
+        );
+        let low_vars = self.num_vars - position - values.len();
+        let block = eq_table(values);
+        let low_len = 1 << low_vars;
+        let chunk_len = block.len() * low_len;
+
+        let mut representation = Vec::with_capacity(self.representation.len() / block.len());
+        for chunk in self.representation.chunks(chunk_len) {
+            let start = representation.len();
+            representation.resize(start + low_len, F::zero());
+            for (row, eq) in chunk.chunks(low_len).zip(&block) {
+                for (out, value) in representation[start..].iter_mut().zip(row) {
+                    *out += *eq * value;
+                }
+            }
+        }
+
+        EvaluationFormPolynomial {
+            representation,
+            num_vars: self.num_vars - values.len(),
+        }
+    }
+
+    /// Splits `f(x, y)`, where `x` is the first `at` variables, into the polynomials `f(x, ·)`
+    /// for every point `x` of the hypercube, in hypercube order.
+    pub fn split_variables(&self, at: usize) -> Vec<Self> {
+        assert!(at <= self.num_vars, "split point out of range");
+        let num_vars = self.num_vars - at;
+        self.representation
+            .chunks(1 << num_vars)
+            .map(|chunk| EvaluationFormPolynomial {
+                representation: chunk.to_vec(),
+                num_vars,
+            })
+            .collect()
+    }
+
+    /// Inverse of `split_variables`: combines `2^k` polynomials over the same variables `y`
+    /// into one polynomial `f(x, y)` with `k` new leading variables `x`.
+    pub fn merge_variables(parts: &[Self]) -> Self {
+        assert!(
+            parts.len().is_power_of_two(),
+            "number of parts must be a power of 2"
+        );
+        let num_vars = parts[0].num_vars;
+        assert!(
+            parts.iter().all(|part| part.num_vars == num_vars),
+            "all parts must have the same number of variables"
+        );
+        EvaluationFormPolynomial {
+            representation: parts
+                .iter()
+                .flat_map(|part| part.representation.iter().copied())
+                .collect(),
+            num_vars: num_vars + parts.len().ilog2() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(num_vars: usize) -> EvaluationFormPolynomial<Fq> {
+        EvaluationFormPolynomial::new(&(0..1u64 << num_vars).map(|i| Fq::from(i * i + 3)).collect())
+    }
+
+    fn point(values: &[u64]) -> Vec<Fq> {
+        values.iter().map(|v| Fq::from(*v)).collect()
+    }
+
+    #[test]
+    fn test_permute_and_transpose() {
+        let f = poly(3);
+        let g = f.permute_variables(&[2, 0, 1]);
+        // g(x0, x1, x2) = f(x1, x2, x0)
+        assert_eq!(
+            g.evaluate(&point(&[5, 7, 11])),
+            f.evaluate(&point(&[7, 11, 5]))
+        );
+
+        // h(y0, y1, x0) = f(x0, y0, y1)
+        let h = f.transpose(1);
+        assert_eq!(
+            h.evaluate(&point(&[7, 11, 5])),
+            f.evaluate(&point(&[5, 7, 11]))
+        );
+        assert_eq!(h.transpose(2), f);
+    }
+
+    #[test]
+    fn test_fix_variables() {
+        let f = poly(5);
+        let values = point(&[3, 8]);
+
+        // a block
+        let expected = f
+            .partial_evaluate(values[0], 0)
+            .partial_evaluate(values[1], 0);
+        assert_eq!(f.fix_variables(&values, 0), expected);
+
+        // middle block
+        let expected = f
+            .partial_evaluate(values[0], 2)
+            .partial_evaluate(values[1], 2);
+        assert_eq!(f.fix_variables(&values, 2), expected);
+
+        // c block
+        let expected = f
+            .partial_evaluate(values[0], 3)
+            .partial_evaluate(values[1], 3);
+        assert_eq!(f.fix_variables(&values, 3), expected);
+    }
+
+    #[test]
+    fn test_split_and_merge_variables() {
+        let f = poly(3);
+        let parts = f.split_variables(1);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[1], f.partial_evaluate(Fq::from(1), 0));
+        assert_eq!(EvaluationFormPolynomial::merge_variables(&parts), f);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::eq_poly::eq_table;
+use crate::error::PolynomialError;
+use crate::product_poly::{ProductPolynomial, SumPolynomial};
+use crate::EvaluationFormPolynomial;
+use ark_ff::PrimeField;
+use std::rc::Rc;
+
+/// A linear combination of products of multilinear polynomials,
+/// `Σ_j c_j · ∏_{k ∈ S_j} f_k(x)`, over a common set of variables.
+///
+/// The multilinear factors are stored once in `mles` and shared between products through
+/// reference counting, so a factor appearing in several products is only folded once per
+/// sumcheck round.
+#[derive(Debug, Clone)]
+pub struct VirtualPolynomial<F: PrimeField> {
+    pub num_vars: usize,
+    /// Each product as its coefficient and the indices of its factors in `mles`.
+    pub products: Vec<(F, Vec<usize>)>,
+    pub mles: Vec<Rc<EvaluationFormPolynomial<F>>>,
+}
+
+impl<F: PrimeField> VirtualPolynomial<F> {
+    pub fn new(num_vars: usize) -> Self {
+        VirtualPolynomial {
+            num_vars,
+            products: vec![],
+            mles: vec![],
+        }
+    }
+
+    /// Adds `coefficient · ∏ factors` to the polynomial. Factors that are already part of the
+    /// polynomial (the same `Rc`) are reused rather than stored again.
+    pub fn add_product(
+        &mut self,
+        coefficient: F,
+        factors: impl IntoIterator<Item = Rc<EvaluationFormPolynomial<F>>>,
+    ) -> Result<(), PolynomialError> {
+        // Check every factor before interning any, so a rejected product leaves no orphaned MLEs
+        let factors: Vec<_> = factors.into_iter().collect();
+        if let Some(factor) = factors.iter().find(|f| f.num_vars != self.num_vars) {
+            return Err(PolynomialError::VariableCountMismatch {
+                left: self.num_vars,
+                right: factor.num_vars,
+            });
+        }
+
+        let mut indices = vec![];
+        for factor in factors {
+            let index = match self.mles.iter().position(|mle| Rc::ptr_eq(mle, &factor)) {
+                Some(index) => index,
+                None => {
+                    self.mles.push(factor);
+                    self.mles.len() - 1
+                }
+            };
+            indices.push(index);
+        }
+        self.products.push((coefficient, indices));
+        Ok(())
+    }
+
+    /// The largest number of factors in any product, i.e. the degree of the polynomial in
+    /// each individual variable.
+    pub fn max_degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn combine(&self, mle_values: &[F]) -> F {
+        self.products
+            .iter()
+            .map(|(coefficient, indices)| {
+                indices
+                    .iter()
+                    .fold(*coefficient, |acc, index| acc * mle_values[*index])
+            })
+            .sum()
+    }
+
+    /// Evaluates the polynomial at `point`, evaluating every shared factor only once.
+    pub fn evaluate(&self, point: &[F]) -> F {
+        assert_eq!(
+            point.len(),
+            self.num_vars,
+            "point must have one coordinate per variable"
+        );
+        let table = eq_table(point);
+        let mle_values: Vec<F> = self
+            .mles
+            .iter()
+            .map(|mle| mle.inner_product(&table))
+            .collect();
+        self.combine(&mle_values)
+    }
+
+    /// Sum of the polynomial over the boolean hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        self.hypercube_evaluations().sum()
+    }
+
+    /// The values of the polynomial on the boolean hypercube, i.e. every product multiplied
+    /// out into a single table. The result is multilinear only when every product has at most
+    /// one factor, but it has the same sum and the same evaluations on the hypercube.
+    pub fn to_evaluation_form(&self) -> EvaluationFormPolynomial<F> {
+        EvaluationFormPolynomial::new(&self.hypercube_evaluations().collect())
+    }
+
+    fn hypercube_evaluations(&self) -> impl Iterator<Item = F> + '_ {
+        (0..1 << self.num_vars).map(|i| {
+            let mle_values: Vec<F> = self.mles.iter().map(|mle| mle.representation[i]).collect();
+            self.combine(&mle_values)
+        })
+    }
+
+    /// Evaluations at `t = 0, 1, ..., max_degree` of the univariate polynomial
+    /// `g(t) = Σ_{x ∈ {0,1}^(n-1)} p(t, x)`, i.e. the prover's message in a sumcheck round.
+    pub fn round_evaluations(&self) -> Vec<F> {
+        assert!(self.num_vars > 0, "no variables left to sum over");
+        let half = 1 << (self.num_vars - 1);
+        let mut evaluations = vec![F::zero(); self.max_degree() + 1];
+        let mut mle_values = vec![F::zero(); self.mles.len()];
+
+        for i in 0..half {
+            for (t, evaluation) in evaluations.iter_mut().enumerate() {
+                let t = F::from(t as u64);
+                for (value, mle) in mle_values.iter_mut().zip(&self.mles) {
+                    let lo = mle.representation[i];
+                    let hi = mle.representation[i + half];
+                    *value = lo + t * (hi - lo);
+                }
+                *evaluation += self.combine(&mle_values);
+            }
+        }
+        evaluations
+    }
+
+    /// Fixes the first variable of every factor to `value`, keeping the sharing between
+    /// products.
+    pub fn fix_variable(&self, value: F) -> Self {
+        self.partial_evaluate(value, 0)
+    }
+
+    /// Fixes the variable at `position` of every factor to `value`.
+    pub fn partial_evaluate(&self, value: F, position: usize) -> Self {
+        VirtualPolynomial {
+            num_vars: self.num_vars - 1,
+            products: self.products.clone(),
+            mles: self
+                .mles
+                .iter()
+                .map(|mle| Rc::new(mle.partial_evaluate(value, position)))
+                .collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<&ProductPolynomial<F>> for VirtualPolynomial<F> {
+    fn from(product: &ProductPolynomial<F>) -> Self {
+        VirtualPolynomial::from(&SumPolynomial::new(vec![product.clone()]))
+    }
+}
+
+impl<F: PrimeField> From<&SumPolynomial<F>> for VirtualPolynomial<F> {
+    /// Panics if the factors do not all have the same number of variables.
+    fn from(sum: &SumPolynomial<F>) -> Self {
+        let num_vars = sum.polyomials[0].polyomials[0].num_vars;
+        let mut result = VirtualPolynomial::new(num_vars);
+        for product in &sum.polyomials {
+            result
+                .add_product(F::one(), product.polyomials.iter().cloned().map(Rc::new))
+                .unwrap_or_else(|e| panic!("{}", e));
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn poly(values: &[u64]) -> Rc<EvaluationFormPolynomial<Fq>> {
+        Rc::new(EvaluationFormPolynomial::new(
+            &values.iter().map(|v| Fq::from(*v)).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_virtual_polynomial() {
+        let f = poly(&[0, 3, 2, 5]);
+        let g = poly(&[1, 0, 0, 4]);
+        let h = poly(&[2, 7, 1, 1]);
+
+        // 2·f·g·h + 3·f·f + 5·h
+        let mut p = VirtualPolynomial::new(2);
+        p.add_product(Fq::from(2), [f.clone(), g.clone(), h.clone()])
+            .unwrap();
+        p.add_product(Fq::from(3), [f.clone(), f.clone()]).unwrap();
+        p.add_product(Fq::from(5), [h.clone()]).unwrap();
+
+        assert_eq!(p.mles.len(), 3);
+        assert_eq!(p.max_degree(), 3);
+
+        let expected = |point: &[Fq]| {
+            let (f, g, h) = (f.evaluate(point), g.evaluate(point), h.evaluate(point));
+            Fq::from(2) * f * g * h + Fq::from(3) * f * f + Fq::from(5) * h
+        };
+        let point = [Fq::from(4), Fq::from(9)];
+        assert_eq!(p.evaluate(&point), expected(&point));
+
+        let sum: Fq = (0..2u64)
+            .flat_map(|a| (0..2u64).map(move |b| [Fq::from(a), Fq::from(b)]))
+            .map(|point| expected(&point))
+            .sum();
+        assert_eq!(p.sum_over_hypercube(), sum);
+
+        let round = p.round_evaluations();
+        assert_eq!(round.len(), 4);
+        assert_eq!(round[0] + round[1], sum);
+        let folded = p.fix_variable(Fq::from(3));
+        assert_eq!(round[3], folded.sum_over_hypercube());
+        assert_eq!(
+            folded.evaluate(&[Fq::from(9)]),
+            expected(&[Fq::from(3), Fq::from(9)])
+        );
+    }
+
+    #[test]
+    fn test_from_sum_polynomial() {
+        let a = EvaluationFormPolynomial::new(&vec![
+            Fq::from(0),
+            Fq::from(3),
+            Fq::from(2),
+            Fq::from(5),
+        ]);
+        let b = EvaluationFormPolynomial::new(&vec![
+            Fq::from(0),
+            Fq::from(0),
+            Fq::from(0),
+            Fq::from(4),
+        ]);
+        let sum = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![a.clone(), b.clone()]),
+            ProductPolynomial::new(vec![a.clone(), b.clone(), a.clone()]),
+        ]);
+        let p = VirtualPolynomial::from(&sum);
+        assert_eq!(p.max_degree(), 3);
+        let point = [Fq::from(5), Fq::from(2)];
+        let (a, b) = (a.evaluate(&point), b.evaluate(&point));
+        assert_eq!(p.evaluate(&point), a * b + a * b * a);
+    }
+
+    #[test]
+    fn test_add_product_mismatch() {
+        let mut p = VirtualPolynomial::new(3);
+        assert_eq!(
+            p.add_product(Fq::from(1), [poly(&[1, 2])]),
+            Err(PolynomialError::VariableCountMismatch { left: 3, right: 1 })
+        );
+
+        // A later factor failing the check must not leave the earlier ones behind
+        let f = poly(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert!(p.add_product(Fq::from(1), [f, poly(&[1, 2])]).is_err());
+        assert!(p.mles.is_empty() && p.products.is_empty());
+    }
+}
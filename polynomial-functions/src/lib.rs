@@ -1,6 +1,6 @@
 use ark_ff::PrimeField;
 
-use std::ops::{Add, Mul};
+use std::ops::{Add, Mul, Sub};
 
 /// Represents a single term in a polynomial, consisting of an exponent and a coefficient.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -27,12 +27,14 @@ impl<F: PrimeField> Monomial<F> {
             coefficients,
         }
     }
+}
+impl<F: PrimeField> Default for Monomial<F> {
     /// Creates a default `Monomial` with an exponent of 0 and a coefficient of 0.0.
     ///
     /// # Returns
     ///
     /// A default `Monomial` instance.
-    pub fn default() -> Monomial<F> {
+    fn default() -> Monomial<F> {
         Monomial {
             exponent: 0,
             coefficients: F::zero(),
@@ -47,6 +49,19 @@ pub struct UnivariatePolynomial<F: PrimeField> {
     /// The degree of the polynomial, if known.
     pub degree: Option<u32>,
 }
+impl<F: PrimeField> Default for UnivariatePolynomial<F> {
+    /// Creates a default `Polynomial` with no monomials and no degree.
+    ///
+    /// # Returns
+    ///
+    /// A default `Polynomial` instance.
+    fn default() -> UnivariatePolynomial<F> {
+        UnivariatePolynomial {
+            monomials: Vec::new(),
+            degree: None,
+        }
+    }
+}
 impl<F: PrimeField> UnivariatePolynomial<F> {
     /// Adds a monomial to the polynomial. If a monomial with the same exponent already exists,
     /// their coefficients are combined.
@@ -55,25 +70,12 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
     ///
     /// * `exponent` - The exponent of the monomial to add.
     /// * `coefficients` - The coefficient of the monomial to add.
-
     pub fn new(monomials: Vec<Monomial<F>>) -> UnivariatePolynomial<F> {
         UnivariatePolynomial {
             monomials,
             degree: None,
         }
     }
-    /// Creates a default `Polynomial` with no monomials and no degree.
-    ///
-    /// # Returns
-    ///
-    /// A default `Polynomial` instance.
-    pub fn default() -> UnivariatePolynomial<F> {
-        UnivariatePolynomial {
-            monomials: Vec::new(),
-            degree: None,
-        }
-    }
-
     /// Evaluates the polynomial at a given value of `x`.
     ///
     /// # Arguments
@@ -88,9 +90,9 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
         let n = self.monomials.len();
         for i in 0..n {
             result += self.monomials[i].coefficients
-                * F::from(x).pow(&[self.monomials[i].exponent as u64]);
+                * x.pow([self.monomials[i].exponent as u64]);
         }
-        return result;
+        result
     }
 
     /// Returns the degree of the polynomial.
@@ -106,9 +108,9 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
                     self.degree = Some(self.monomials[i].exponent as u32);
                 }
             }
-            return self.degree;
+            self.degree
         } else {
-            return self.degree;
+            self.degree
         }
     }
     /// Performs Lagrange interpolation to find a polynomial that passes through the given points.
@@ -121,41 +123,354 @@ impl<F: PrimeField> UnivariatePolynomial<F> {
     /// # Returns
     ///
     /// A `UnivariatePolynomial` that passes through the given points.
-    //add prime modulo
     pub fn interpolate(x: Vec<F>, y: Vec<F>) -> UnivariatePolynomial<F> {
-        let n = x.len();
-        let mut result = UnivariatePolynomial::default();
+        assert_eq!(x.len(), y.len(), "x and y must have the same length");
+        let mut result = vec![F::zero(); x.len()];
 
-        // result.set_prime_modulo(p);
+        for (i, y_i) in y.iter().enumerate() {
+            let basis = UnivariatePolynomial::lagrange_basis(&x, i).coefficients();
+            for (coeff, b) in result.iter_mut().zip(basis) {
+                *coeff += *y_i * b;
+            }
+        }
 
-        for i in 0..n {
-            let mut denominator = F::from(1);
+        UnivariatePolynomial::from_coefficients(result)
+    }
 
-            let mut numerator = UnivariatePolynomial::new(vec![Monomial::new(0, F::one())]);
+    /// Constructs the vanishing polynomial `Z_S(x) = ∏(x - s)` of a set of points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points `s` at which the polynomial vanishes.
+    ///
+    /// # Returns
+    ///
+    /// A monic `UnivariatePolynomial` of degree `points.len()` whose roots are `points`.
+    pub fn vanishing(points: &[F]) -> UnivariatePolynomial<F> {
+        let mut coefficients = vec![F::one()];
+        for point in points {
+            // Multiply by (x - point)
+            let mut next = vec![F::zero(); coefficients.len() + 1];
+            for (i, coeff) in coefficients.iter().enumerate() {
+                next[i + 1] += coeff;
+                next[i] -= *coeff * point;
+            }
+            coefficients = next;
+        }
+        UnivariatePolynomial::from_coefficients(coefficients)
+    }
 
-            let mut a = y[i];
-            for j in 0..n {
-                if i != j {
-                    let x_n = Monomial::new(1, F::one()); // x
-                    let x_j = Monomial::new(0, F::from(-x[j]));
-                    let temp_poly = UnivariatePolynomial::new(vec![x_n, x_j]);
+    /// Returns the elements `1, ω, ω^2, ..., ω^(n-1)` of the multiplicative subgroup of size `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of the subgroup. The field must contain a primitive `n`-th root of unity.
+    ///
+    /// # Returns
+    ///
+    /// The subgroup elements in order of increasing powers of the generator.
+    pub fn subgroup_elements(n: usize) -> Vec<F> {
+        let generator = F::get_root_of_unity(n as u64)
+            .expect("The field has no multiplicative subgroup of the given size");
+        let mut elements = Vec::with_capacity(n);
+        let mut current = F::one();
+        for _ in 0..n {
+            elements.push(current);
+            current *= generator;
+        }
+        elements
+    }
 
-                    numerator = numerator * temp_poly;
+    /// Constructs the vanishing polynomial `x^n - 1` of the multiplicative subgroup of size `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of the subgroup.
+    ///
+    /// # Returns
+    ///
+    /// A `UnivariatePolynomial` representing `x^n - 1`.
+    pub fn vanishing_subgroup(n: usize) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::new(vec![
+            Monomial::new(n, F::one()),
+            Monomial::new(0, -F::one()),
+        ])
+    }
 
-                    denominator = denominator * (F::from(x[i]) - F::from(x[j]));
-                }
-            }
+    /// Constructs the `i`-th Lagrange basis polynomial over a set of points, which is `1` at
+    /// `points[i]` and `0` at every other point.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The distinct interpolation points.
+    /// * `i` - The index of the point at which the basis polynomial is `1`.
+    ///
+    /// # Returns
+    ///
+    /// A `UnivariatePolynomial` of degree `points.len() - 1`.
+    pub fn lagrange_basis(points: &[F], i: usize) -> UnivariatePolynomial<F> {
+        assert!(i < points.len(), "Index out of range of the points");
+        let others: Vec<F> = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, point)| *point)
+            .collect();
+
+        let denominator: F = others.iter().map(|point| points[i] - point).product();
+        let denominator_inverse = denominator
+            .inverse()
+            .expect("Interpolation points must be distinct");
+
+        let numerator = UnivariatePolynomial::vanishing(&others).coefficients();
+        UnivariatePolynomial::from_coefficients(
+            numerator
+                .into_iter()
+                .map(|coeff| coeff * denominator_inverse)
+                .collect(),
+        )
+    }
+
+    /// Constructs the `i`-th Lagrange basis polynomial over the multiplicative subgroup of
+    /// size `n`, using the closed form `L_i(x) = ω^i / n * (x^n - 1) / (x - ω^i)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The size of the subgroup.
+    /// * `i` - The index of the subgroup element `ω^i` at which the basis polynomial is `1`.
+    ///
+    /// # Returns
+    ///
+    /// A `UnivariatePolynomial` of degree `n - 1`.
+    pub fn subgroup_lagrange_basis(n: usize, i: usize) -> UnivariatePolynomial<F> {
+        assert!(i < n, "Index out of range of the subgroup");
+        let omega_i = Self::subgroup_elements(n)[i];
+        let divisor = UnivariatePolynomial::from_coefficients(vec![-omega_i, F::one()]);
+        let (quotient, _) = UnivariatePolynomial::vanishing_subgroup(n).div_rem(&divisor);
+
+        let scale = omega_i * F::from(n as u64).inverse().unwrap();
+        UnivariatePolynomial::from_coefficients(
+            quotient
+                .coefficients()
+                .into_iter()
+                .map(|coeff| coeff * scale)
+                .collect(),
+        )
+    }
+
+    /// Returns the dense coefficient vector of the polynomial, where index `i` holds the
+    /// coefficient of `x^i`. Trailing zero coefficients are trimmed.
+    ///
+    /// # Returns
+    ///
+    /// A vector of coefficients in ascending order of exponent.
+    pub fn coefficients(&self) -> Vec<F> {
+        let len = self
+            .monomials
+            .iter()
+            .map(|monomial| monomial.exponent + 1)
+            .max()
+            .unwrap_or(0);
+        let mut coefficients = vec![F::zero(); len];
+        for monomial in &self.monomials {
+            coefficients[monomial.exponent] += monomial.coefficients;
+        }
+        while coefficients.last().is_some_and(|c| c.is_zero()) {
+            coefficients.pop();
+        }
+        coefficients
+    }
+
+    /// Creates a polynomial from a dense coefficient vector, where index `i` holds the
+    /// coefficient of `x^i`. Zero coefficients are skipped and the monomials are stored
+    /// from the highest exponent down.
+    ///
+    /// # Arguments
+    ///
+    /// * `coefficients` - The coefficients in ascending order of exponent.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnivariatePolynomial` instance.
+    pub fn from_coefficients(coefficients: Vec<F>) -> UnivariatePolynomial<F> {
+        let monomials = coefficients
+            .into_iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(exponent, coeff)| Monomial::new(exponent, coeff))
+            .collect();
+        UnivariatePolynomial::new(monomials)
+    }
+
+    /// Returns `true` if every coefficient of the polynomial is zero.
+    pub fn is_zero(&self) -> bool {
+        self.monomials
+            .iter()
+            .all(|monomial| monomial.coefficients.is_zero())
+    }
+
+    /// Returns the degree of the polynomial, ignoring monomials with a zero coefficient.
+    ///
+    /// # Returns
+    ///
+    /// The degree of the polynomial, or `None` for the zero polynomial.
+    pub fn actual_degree(&self) -> Option<usize> {
+        self.coefficients().len().checked_sub(1)
+    }
 
-            a /= denominator;
+    /// Computes the formal derivative of the polynomial.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnivariatePolynomial` representing `p'(x)`.
+    pub fn derivative(&self) -> UnivariatePolynomial<F> {
+        let coefficients = self.coefficients();
+        let derivative = coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(exponent, coeff)| F::from(exponent as u64) * coeff)
+            .collect();
+        UnivariatePolynomial::from_coefficients(derivative)
+    }
+
+    /// Composes the polynomial with another one, computing `p(q(x))` using Horner's rule.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The inner polynomial `q(x)`.
+    ///
+    /// # Returns
+    ///
+    /// A new `UnivariatePolynomial` representing `p(q(x))`.
+    pub fn compose(&self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        let mut result = UnivariatePolynomial::default();
+        for coeff in self.coefficients().into_iter().rev() {
+            result = result * other.clone() + UnivariatePolynomial::from_coefficients(vec![coeff]);
+        }
+        UnivariatePolynomial::from_coefficients(result.coefficients())
+    }
+
+    /// Divides the polynomial by `divisor` using polynomial long division.
+    ///
+    /// # Arguments
+    ///
+    /// * `divisor` - The polynomial to divide by. Must not be the zero polynomial.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(quotient, remainder)` such that `self = quotient * divisor + remainder`
+    /// and the degree of `remainder` is less than the degree of `divisor`.
+    pub fn div_rem(
+        &self,
+        divisor: &UnivariatePolynomial<F>,
+    ) -> (UnivariatePolynomial<F>, UnivariatePolynomial<F>) {
+        let divisor = divisor.coefficients();
+        assert!(!divisor.is_empty(), "Cannot divide by the zero polynomial");
+
+        let mut remainder = self.coefficients();
+        if remainder.len() < divisor.len() {
+            return (
+                UnivariatePolynomial::default(),
+                UnivariatePolynomial::from_coefficients(remainder),
+            );
+        }
 
-            for monomial in &mut numerator.monomials {
-                monomial.coefficients *= F::from(a);
+        let lead_inverse = divisor[divisor.len() - 1].inverse().unwrap();
+        let mut quotient = vec![F::zero(); remainder.len() - divisor.len() + 1];
+        for i in (0..quotient.len()).rev() {
+            let coeff = remainder[i + divisor.len() - 1] * lead_inverse;
+            quotient[i] = coeff;
+            for (j, d) in divisor.iter().enumerate() {
+                remainder[i + j] -= coeff * d;
             }
+        }
 
-            result = result + numerator;
+        (
+            UnivariatePolynomial::from_coefficients(quotient),
+            UnivariatePolynomial::from_coefficients(remainder),
+        )
+    }
+
+    /// Runs the extended Euclidean algorithm on two polynomials.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first polynomial.
+    /// * `b` - The second polynomial.
+    ///
+    /// # Returns
+    ///
+    /// A tuple `(g, s, t)` where `g` is the monic greatest common divisor of `a` and `b`
+    /// and `a * s + b * t = g`. If both inputs are zero, all three are zero.
+    pub fn extended_gcd(
+        a: &UnivariatePolynomial<F>,
+        b: &UnivariatePolynomial<F>,
+    ) -> (
+        UnivariatePolynomial<F>,
+        UnivariatePolynomial<F>,
+        UnivariatePolynomial<F>,
+    ) {
+        let one = UnivariatePolynomial::from_coefficients(vec![F::one()]);
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        let (mut s0, mut s1) = (one.clone(), UnivariatePolynomial::default());
+        let (mut t0, mut t1) = (UnivariatePolynomial::default(), one);
+
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1);
+            let s = s0 - quotient.clone() * s1.clone();
+            let t = t0 - quotient * t1.clone();
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s);
+            (t0, t1) = (t1, t);
         }
 
-        result
+        match r0.coefficients().last() {
+            Some(lead) => {
+                let lead_inverse = lead.inverse().unwrap();
+                let scale = |p: UnivariatePolynomial<F>| {
+                    UnivariatePolynomial::from_coefficients(
+                        p.coefficients()
+                            .into_iter()
+                            .map(|c| c * lead_inverse)
+                            .collect(),
+                    )
+                };
+                (scale(r0), scale(s0), scale(t0))
+            }
+            None => (
+                UnivariatePolynomial::default(),
+                UnivariatePolynomial::default(),
+                UnivariatePolynomial::default(),
+            ),
+        }
+    }
+
+    /// Computes the monic greatest common divisor of the polynomial and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The polynomial to compute the GCD with.
+    ///
+    /// # Returns
+    ///
+    /// The monic GCD of the two polynomials.
+    pub fn gcd(&self, other: &UnivariatePolynomial<F>) -> UnivariatePolynomial<F> {
+        UnivariatePolynomial::extended_gcd(self, other).0
+    }
+
+    /// Checks whether the polynomial is square-free, i.e. has no repeated roots.
+    /// A polynomial is square-free exactly when `gcd(p, p')` is a constant.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the polynomial has no repeated factors.
+    pub fn is_square_free(&self) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        self.gcd(&self.derivative()).actual_degree() == Some(0)
     }
 }
 
@@ -170,17 +485,16 @@ impl<F: PrimeField> Mul for UnivariatePolynomial<F> {
     /// # Returns
     ///
     /// A new `Polynomial` representing the product of the two polynomials.
-
     fn mul(self, p2: UnivariatePolynomial<F>) -> Self {
         let p1: Vec<Monomial<F>> = self.monomials;
         let p2: Vec<Monomial<F>> = p2.monomials;
 
         let mut polynomial: Vec<Monomial<F>> = Vec::new();
-        for i in 0..p1.len() {
-            for j in 0..p2.len() {
+        for m1 in &p1 {
+            for m2 in &p2 {
                 polynomial.push(Monomial {
-                    coefficients: p1[i].coefficients * p2[j].coefficients,
-                    exponent: p1[i].exponent.wrapping_add(p2[j].exponent),
+                    coefficients: m1.coefficients * m2.coefficients,
+                    exponent: m1.exponent.wrapping_add(m2.exponent),
                 });
             }
         }
@@ -216,12 +530,10 @@ impl<F: PrimeField> Add for UnivariatePolynomial<F> {
     /// # Returns
     ///
     /// A new `Polynomial` representing the sum of the two polynomials.
-
     fn add(self, p2: UnivariatePolynomial<F>) -> Self {
         let p1: Vec<Monomial<F>> = self.monomials;
         let p2: Vec<Monomial<F>> = p2.monomials;
-        let mut polynomial: Vec<Monomial<F>> = Vec::new();
-        polynomial = [p1, p2].concat();
+        let mut polynomial: Vec<Monomial<F>> = [p1, p2].concat();
         // Combine monomials with the same exponent
 
         for i in 0..polynomial.len() {
@@ -243,6 +555,28 @@ impl<F: PrimeField> Add for UnivariatePolynomial<F> {
     }
 }
 
+impl<F: PrimeField> Sub for UnivariatePolynomial<F> {
+    type Output = UnivariatePolynomial<F>;
+    /// Subtracts one polynomial from another and returns the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `p2` - The polynomial to subtract.
+    ///
+    /// # Returns
+    ///
+    /// A new `Polynomial` representing the difference of the two polynomials.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, p2: UnivariatePolynomial<F>) -> Self {
+        let negated: Vec<Monomial<F>> = p2
+            .monomials
+            .into_iter()
+            .map(|monomial| Monomial::new(monomial.exponent, -monomial.coefficients))
+            .collect();
+        self + UnivariatePolynomial::new(negated)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,4 +653,144 @@ mod tests {
         assert_eq!(result.monomials[0].coefficients, Fq::from(1u32));
         assert_eq!(result.monomials[0].exponent, 2);
     }
+
+    /// Tests the formal derivative of a polynomial.
+    #[test]
+    fn test_derivative() {
+        // 3x^3 + 2x^2 + 5 -> 9x^2 + 4x
+        let p = UnivariatePolynomial::from_coefficients(vec![
+            Fq::from(5),
+            Fq::from(0),
+            Fq::from(2),
+            Fq::from(3),
+        ]);
+        let result = p.derivative();
+        assert_eq!(
+            result.coefficients(),
+            vec![Fq::from(0), Fq::from(4), Fq::from(9)]
+        );
+        let constant = UnivariatePolynomial::from_coefficients(vec![Fq::from(7)]);
+        assert!(constant.derivative().is_zero());
+    }
+
+    /// Tests the composition `p(q(x))` of two polynomials.
+    #[test]
+    fn test_compose() {
+        // p(x) = x^2 + 1, q(x) = 2x + 3 -> 4x^2 + 12x + 10
+        let p =
+            UnivariatePolynomial::from_coefficients(vec![Fq::from(1), Fq::from(0), Fq::from(1)]);
+        let q = UnivariatePolynomial::from_coefficients(vec![Fq::from(3), Fq::from(2)]);
+        let result = p.compose(&q);
+        assert_eq!(
+            result.coefficients(),
+            vec![Fq::from(10), Fq::from(12), Fq::from(4)]
+        );
+        assert_eq!(
+            result.evaluate(Fq::from(5)),
+            p.evaluate(q.evaluate(Fq::from(5)))
+        );
+    }
+
+    /// Tests polynomial long division.
+    #[test]
+    fn test_div_rem() {
+        // (x^3 - 2x^2 - 4) / (x - 3) = x^2 + x + 3, remainder 5
+        let p = UnivariatePolynomial::from_coefficients(vec![
+            -Fq::from(4),
+            Fq::from(0),
+            -Fq::from(2),
+            Fq::from(1),
+        ]);
+        let d = UnivariatePolynomial::from_coefficients(vec![-Fq::from(3), Fq::from(1)]);
+        let (quotient, remainder) = p.div_rem(&d);
+        assert_eq!(
+            quotient.coefficients(),
+            vec![Fq::from(3), Fq::from(1), Fq::from(1)]
+        );
+        assert_eq!(remainder.coefficients(), vec![Fq::from(5)]);
+    }
+
+    /// Tests the GCD and the Bezout identity returned by the extended Euclidean algorithm.
+    #[test]
+    fn test_extended_gcd() {
+        // a = (x - 1)(x - 2), b = (x - 1)(x + 3)
+        let x_minus_1 = UnivariatePolynomial::from_coefficients(vec![-Fq::from(1), Fq::from(1)]);
+        let x_minus_2 = UnivariatePolynomial::from_coefficients(vec![-Fq::from(2), Fq::from(1)]);
+        let x_plus_3 = UnivariatePolynomial::from_coefficients(vec![Fq::from(3), Fq::from(1)]);
+        let a = x_minus_1.clone() * x_minus_2;
+        let b = x_minus_1.clone() * x_plus_3;
+
+        let (g, s, t) = UnivariatePolynomial::extended_gcd(&a, &b);
+        assert_eq!(g.coefficients(), x_minus_1.coefficients());
+        assert_eq!(
+            (a.clone() * s + b.clone() * t).coefficients(),
+            g.coefficients()
+        );
+        assert_eq!(a.gcd(&b).coefficients(), x_minus_1.coefficients());
+    }
+
+    /// Tests detection of repeated roots.
+    #[test]
+    fn test_is_square_free() {
+        let x_minus_1 = UnivariatePolynomial::from_coefficients(vec![-Fq::from(1), Fq::from(1)]);
+        let x_minus_2 = UnivariatePolynomial::from_coefficients(vec![-Fq::from(2), Fq::from(1)]);
+        let square_free = x_minus_1.clone() * x_minus_2.clone();
+        let repeated = x_minus_1.clone() * x_minus_1 * x_minus_2;
+        assert!(square_free.is_square_free());
+        assert!(!repeated.is_square_free());
+    }
+
+    /// Tests the vanishing polynomial of an arbitrary set of points.
+    #[test]
+    fn test_vanishing() {
+        let points = vec![Fq::from(1), Fq::from(4), Fq::from(9)];
+        let z = UnivariatePolynomial::vanishing(&points);
+        assert_eq!(z.actual_degree(), Some(3));
+        for point in &points {
+            assert_eq!(z.evaluate(*point), Fq::from(0));
+        }
+        assert_eq!(
+            z.evaluate(Fq::from(2)),
+            Fq::from(1) * (Fq::from(2) - Fq::from(4)) * (Fq::from(2) - Fq::from(9))
+        );
+    }
+
+    /// Tests the vanishing polynomial and Lagrange basis over a multiplicative subgroup.
+    #[test]
+    fn test_subgroup_vanishing_and_lagrange_basis() {
+        // The scalar field has a large power-of-two subgroup, unlike the base field.
+        use ark_bn254::Fr;
+        let n = 8;
+        let domain = UnivariatePolynomial::<Fr>::subgroup_elements(n);
+        let z = UnivariatePolynomial::<Fr>::vanishing_subgroup(n);
+        for element in &domain {
+            assert_eq!(z.evaluate(*element), Fr::from(0));
+        }
+
+        for i in 0..n {
+            let basis = UnivariatePolynomial::<Fr>::subgroup_lagrange_basis(n, i);
+            assert_eq!(
+                basis.coefficients(),
+                UnivariatePolynomial::lagrange_basis(&domain, i).coefficients()
+            );
+            for (j, element) in domain.iter().enumerate() {
+                let expected = if i == j { Fr::from(1) } else { Fr::from(0) };
+                assert_eq!(basis.evaluate(*element), expected);
+            }
+        }
+    }
+
+    /// Tests that a Lagrange basis polynomial is `1` at its own point and `0` elsewhere.
+    #[test]
+    fn test_lagrange_basis() {
+        let points = vec![Fq::from(2), Fq::from(5), Fq::from(7), Fq::from(11)];
+        for i in 0..points.len() {
+            let basis = UnivariatePolynomial::lagrange_basis(&points, i);
+            assert_eq!(basis.actual_degree(), Some(points.len() - 1));
+            for (j, point) in points.iter().enumerate() {
+                let expected = if i == j { Fq::from(1) } else { Fq::from(0) };
+                assert_eq!(basis.evaluate(*point), expected);
+            }
+        }
+    }
 }
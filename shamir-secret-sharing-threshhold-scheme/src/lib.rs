@@ -1,5 +1,4 @@
 use ark_ff::PrimeField;
-use ark_std;
 use polynomials::{Monomial, UnivariatePolynomial};
 /// Sets up the Shamir Secret Sharing scheme.
 ///
@@ -14,7 +13,6 @@ use polynomials::{Monomial, UnivariatePolynomial};
 /// # Returns
 ///
 /// A vector of tuples where each tuple contains a share (x, y).
-
 pub fn setup<F: PrimeField>(secret: F, threshold: usize, n: usize) -> Vec<(F, F)> {
     let mut monomials = Vec::new();
     let m1 = Monomial::new(0, secret);
@@ -56,9 +54,7 @@ pub fn setup<F: PrimeField>(secret: F, threshold: usize, n: usize) -> Vec<(F, F)
 pub fn reconstruct_data<F: PrimeField>(x: Vec<F>, y: Vec<F>) -> F {
     let data_poly: UnivariatePolynomial<F> = UnivariatePolynomial::interpolate(x, y);
 
-    let data = data_poly.evaluate(F::from(0));
-
-    return data;
+    data_poly.evaluate(F::from(0))
 }
 
 /// Sets up the Shamir Secret Sharing scheme with an additional password.
@@ -105,7 +101,7 @@ pub fn passworded_setup<F: PrimeField>(
         let shares_y = polynomial.evaluate(shares_x);
         shares.push((shares_x, shares_y));
     }
-    return shares;
+    shares
 }
 
 /// Reconstructs the secret from the given shares and password using the Shamir Secret Sharing scheme.
@@ -121,7 +117,6 @@ pub fn passworded_setup<F: PrimeField>(
 /// # Returns
 ///
 /// The reconstructed secret.
-
 pub fn reconstruct_data_with_password<F: PrimeField>(x: Vec<F>, y: Vec<F>, password: F) -> F {
     println!("x{}", x.len());
 
@@ -129,7 +124,7 @@ pub fn reconstruct_data_with_password<F: PrimeField>(x: Vec<F>, y: Vec<F>, passw
     println!("data_poly {:?}", data_poly);
     let data = data_poly.evaluate(password);
     println!("data {:?}", data);
-    return data;
+    data
 }
 
 #[cfg(test)]
@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Reasons a sumcheck transcript is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumcheckError {
+    /// The proof does not have one round polynomial per variable.
+    RoundCountMismatch { expected: usize, actual: usize },
+    /// A round polynomial has the wrong number of evaluations for the expected degree.
+    DegreeMismatch {
+        round: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// `p(0) + p(1)` of a round polynomial differs from the claim of the previous round.
+    RoundSumMismatch { round: usize },
+}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SumcheckError::RoundCountMismatch { expected, actual } => write!(
+                f,
+                "expected {} round polynomials, got {}",
+                expected, actual
+            ),
+            SumcheckError::DegreeMismatch {
+                round,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "round {} polynomial has degree {}, expected {}",
+                round, actual, expected
+            ),
+            SumcheckError::RoundSumMismatch { round } => {
+                write!(f, "round {} polynomial does not sum to the claim", round)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SumcheckError {}
@@ -1,155 +1,143 @@
-
-use std::{f32::consts::E, vec};
-use polynomials::UnivariatePolynomial;    
-
-use evaluation_form_poly::{
-    product_poly::{ProductPolynomial, SumPolynomial},
-    EvaluationFormPolynomial,
-};
+use crate::error::SumcheckError;
+use multilinear_polynomial::product_poly::SumPolynomial;
+use multilinear_polynomial::virtual_poly::VirtualPolynomial;
+use polynomials::UnivariatePolynomial;
 
 use ark_ff::{BigInteger, PrimeField};
 use fiat_shamir::{self, FiatShamir};
-use sha3::{digest::typenum::Sum, Digest, Sha3_256};
+use sha3::{Digest, Sha3_256};
 
-pub fn verify<F: PrimeField>(
-    mut init_poly: SumPolynomial<F>,
-    mut claimed_sum: F,
-    mut uni_poly: Vec<Vec<F>>,
-) -> F {
-    let mut first_unipoly = uni_poly[0].clone();
-    first_unipoly.pop().unwrap();
-    let mut uni_polynomial: EvaluationFormPolynomial<F> =
-        EvaluationFormPolynomial::new(&first_unipoly);
-    let mut summed_poly = init_poly.clone().polyomials;
-    assert_eq!(
-        uni_polynomial
-            .partial_evaluate(F::from(0), 0)
-            .representation[0]
-            + uni_polynomial
-                .partial_evaluate(F::from(1), 0)
-                .representation[0],
-        claimed_sum.clone(),
-    );
-    let hash_function = Sha3_256::new();
-    let mut fiat_shamir = FiatShamir::new(hash_function);
-    let init_polynomial_bytes: Vec<u8> = summed_poly
+fn to_bytes<F: PrimeField>(values: &[F]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|f| f.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
+        .collect()
+}
+
+fn init_transcript<F: PrimeField>(
+    init_poly: &SumPolynomial<F>,
+    claimed_sum: F,
+) -> FiatShamir<Sha3_256, F> {
+    let mut fiat_shamir = FiatShamir::new(Sha3_256::new());
+    let init_polynomial_bytes: Vec<u8> = init_poly
+        .polyomials
         .iter()
         .flat_map(|f| {
-            f.polyomials.iter().flat_map(|p| {
-                p.representation
-                    .iter()
-                    .flat_map(|elem| elem.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
-            })
+            f.polyomials
+                .iter()
+                .flat_map(|p| to_bytes(&p.representation))
         })
         .collect();
-
     fiat_shamir.absorb(&init_polynomial_bytes);
-    let claimed_sum_bytes: Vec<u8> = claimed_sum
-        .into_bigint()
-        .to_bits_be()
-        .into_iter()
-        .map(|b| b as u8)
-        .collect();
-    fiat_shamir.absorb(&claimed_sum_bytes);
+    fiat_shamir.absorb(&to_bytes(&[claimed_sum]));
+    fiat_shamir
+}
 
-    for i in 0..uni_poly.len() {
-        fiat_shamir.absorb(
-            &uni_poly[i]
-                .iter()
-                .flat_map(|f| f.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
-                .collect::<Vec<u8>>(),
-        );
+/// Degree of `poly` in each variable: the largest number of factors of any of its products.
+fn max_degree<F: PrimeField>(poly: &SumPolynomial<F>) -> usize {
+    poly.polyomials
+        .iter()
+        .map(|product| product.degree())
+        .max()
+        .unwrap_or(0)
+}
 
-        let challenge: F = fiat_shamir.squeeze();
-        uni_poly[i].pop().unwrap();
+/// Evaluates the round polynomial given by its evaluations at `0, 1, ..., d` at `x`.
+fn evaluate_round_poly<F: PrimeField>(evaluations: &[F], x: F) -> F {
+    let x_s: Vec<F> = (0..evaluations.len()).map(|i| F::from(i as u64)).collect();
+    UnivariatePolynomial::interpolate(x_s, evaluations.to_vec()).evaluate(x)
+}
 
-        let uni_poly = SumPolynomial::new(vec![ProductPolynomial::new(vec![
-            EvaluationFormPolynomial::new(&uni_poly[i]),
-        ])]);
-        
+/// Runs the sumcheck prover over every variable of `poly`, drawing the challenges from a
+/// transcript shared with the surrounding protocol.
+///
+/// # Returns
+///
+/// The round polynomials, as their evaluations at `0, 1, ..., degree`, and the challenges.
+pub fn prove_rounds<K: Digest + Clone, F: PrimeField>(
+    mut poly: SumPolynomial<F>,
+    transcript: &mut FiatShamir<K, F>,
+) -> (Vec<Vec<F>>, Vec<F>) {
+    let no_of_variables = poly.polyomials[0].polyomials[0].num_vars;
 
-        claimed_sum = uni_poly.partial_evaluate(challenge.pow([2]), 0).polyomials[0].polyomials[0]
-            .representation[0];
+    let mut round_polys = vec![];
+    let mut challenges = vec![];
+    for _ in 0..no_of_variables {
+        let round_poly = proof_engine(&poly);
+        transcript.absorb(&to_bytes(&round_poly));
+        let challenge = transcript.squeeze();
 
-        init_poly = init_poly.partial_evaluate(challenge, 0);
+        poly = poly.partial_evaluate(challenge, 0);
+        round_polys.push(round_poly);
+        challenges.push(challenge);
     }
-    assert_eq!(
-        init_poly.reduce().polyomials[0].polyomials[0].representation[0],
-        claimed_sum
-    );
 
-    claimed_sum
+    (round_polys, challenges)
 }
 
-pub fn proof<F: PrimeField>(mut init_poly: SumPolynomial<F>, claimed_sum: F) -> (F, Vec<Vec<F>>) {
-    let init_poly_rep = &init_poly.polyomials[0].polyomials[0].representation;
-    let no_of_variables = init_poly_rep.len().ilog2();
+/// Checks the round polynomials of a sumcheck claiming `claimed_sum` over `num_vars` variables
+/// for a polynomial of degree at most `degree` in each variable, drawing the challenges from
+/// a transcript shared with the surrounding protocol.
+///
+/// # Returns
+///
+/// The value the summed polynomial must take at the challenges, and the challenges. The caller
+/// is responsible for checking that final evaluation.
+pub fn verify_rounds<K: Digest + Clone, F: PrimeField>(
+    mut claimed_sum: F,
+    round_polys: &[Vec<F>],
+    num_vars: usize,
+    degree: usize,
+    transcript: &mut FiatShamir<K, F>,
+) -> Result<(F, Vec<F>), SumcheckError> {
+    if round_polys.len() != num_vars {
+        return Err(SumcheckError::RoundCountMismatch {
+            expected: num_vars,
+            actual: round_polys.len(),
+        });
+    }
 
-    let mut summed_poly = init_poly.clone().polyomials;
+    let mut challenges = vec![];
+    for (round, round_poly) in round_polys.iter().enumerate() {
+        if round_poly.len() != degree + 1 {
+            return Err(SumcheckError::DegreeMismatch {
+                round,
+                expected: degree,
+                actual: round_poly.len().saturating_sub(1),
+            });
+        }
+        if round_poly[0] + round_poly[1] != claimed_sum {
+            return Err(SumcheckError::RoundSumMismatch { round });
+        }
 
-    let hash_function = Sha3_256::new();
-    let mut fiat_shamir: FiatShamir<sha3::digest::core_api::CoreWrapper<sha3::Sha3_256Core>, F> =
-        FiatShamir::new(hash_function);
-    let init_polynomial_bytes: Vec<u8> = summed_poly
-        .iter()
-        .flat_map(|f| {
-            f.polyomials.iter().flat_map(|p| {
-                p.representation
-                    .iter()
-                    .flat_map(|elem| elem.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
-            })
-        })
-        .collect();
-    fiat_shamir.absorb(&init_polynomial_bytes);
-    let claimed_sum_bytes: Vec<u8> = claimed_sum
-        .into_bigint()
-        .to_bits_be()
-        .into_iter()
-        .map(|b| b as u8)
-        .collect();
-    fiat_shamir.absorb(&claimed_sum_bytes);
-
-    let mut unipoly_vec = vec![];
-    
-    for _ in 0..no_of_variables {
-        let mut uni_polynomial_eval = proof_engine(&init_poly);
-        unipoly_vec.push(uni_polynomial_eval.clone());
+        transcript.absorb(&to_bytes(round_poly));
+        let challenge = transcript.squeeze();
+        claimed_sum = evaluate_round_poly(round_poly, challenge);
+        challenges.push(challenge);
+    }
 
-        fiat_shamir.absorb(
-            &uni_polynomial_eval
-                .iter()
-                .flat_map(|f: &F| f.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
-                .collect::<Vec<u8>>(),
-        );
-        let challenge = fiat_shamir.squeeze();
+    Ok((claimed_sum, challenges))
+}
 
-        let x_s: Vec<F> = (0..=2).map(|i| F::from(i as u64)).collect();
+/// Proves that `init_poly` sums to `claimed_sum` over the boolean hypercube, panicking if it
+/// does not, since the transcript would otherwise commit to a sum the proof does not show.
+pub fn proof<F: PrimeField>(
+    init_poly: SumPolynomial<F>,
+    claimed_sum: F,
+) -> (F, Vec<Vec<F>>, Vec<F>) {
+    assert_eq!(
+        VirtualPolynomial::from(&init_poly).sum_over_hypercube(),
+        claimed_sum,
+        "claimed sum does not match the polynomial"
+    );
+    let mut fiat_shamir = init_transcript(&init_poly, claimed_sum);
+    let (round_polys, challenges) = prove_rounds(init_poly, &mut fiat_shamir);
 
-        let uni_polynomial =  UnivariatePolynomial::interpolate(x_s, uni_polynomial_eval);
-        let eval_at_0 = uni_polynomial.evaluate(F::zero());
-        let eval_at_1 = uni_polynomial.evaluate(F::one());
-        let verifier_sum = eval_at_0 + eval_at_1;       
-      
-        assert_eq!(
-            init_poly
-                .reduce()
-                .polyomials
-                .iter()
-                .flat_map(|poly| poly.polyomials.iter().flat_map(|p| p.representation.iter()))
-                .sum::<F>(),
-            verifier_sum
-        );
-          init_poly = init_poly.partial_evaluate(challenge, 0);
-            
-    }
-    (claimed_sum, unipoly_vec)
+    (claimed_sum, round_polys, challenges)
 }
 
-fn proof_engine<F: PrimeField>( poly: &SumPolynomial<F>) -> Vec<F> {
-    let init_poly = poly.polyomials.clone();
-
-    let degree = init_poly[0].degree() + 1;
-    println!("degree {:?}", degree);
+fn proof_engine<F: PrimeField>(poly: &SumPolynomial<F>) -> Vec<F> {
+    let degree = max_degree(poly) + 1;
     let mut res_vec = SumPolynomial::new(vec![]);
 
     for i in 0..degree {
@@ -170,13 +158,35 @@ fn proof_engine<F: PrimeField>( poly: &SumPolynomial<F>) -> Vec<F> {
     result
 }
 
+/// Verifies a sumcheck proof with oracle access to `init_poly`, panicking if it does not hold.
+pub fn verify<F: PrimeField>(
+    init_poly: SumPolynomial<F>,
+    claimed_sum: F,
+    uni_poly: Vec<Vec<F>>,
+) -> (F, Vec<F>) {
+    let mut fiat_shamir = init_transcript(&init_poly, claimed_sum);
+    let num_vars = init_poly.polyomials[0].polyomials[0].num_vars;
+    let degree = max_degree(&init_poly);
+
+    let (final_claim, challenges) =
+        verify_rounds(claimed_sum, &uni_poly, num_vars, degree, &mut fiat_shamir)
+            .unwrap_or_else(|e| panic!("{}", e));
+
+    let evaluation: F = init_poly
+        .polyomials
+        .into_iter()
+        .map(|product| product.evaluate(challenges.clone()))
+        .sum();
+    assert_eq!(evaluation, final_claim);
+
+    (final_claim, challenges)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use ark_bn254::Fq;
-    use field_tracker::{Ft, start_tscope, end_tscope, print_summary, summary};
-    use evaluation_form_poly::product_poly::ProductPolynomial;
-    type Fr = Ft!(ark_bn254::Fq);
+    use ark_bn254::Fq as Fr;
+    use multilinear_polynomial::{product_poly::ProductPolynomial, EvaluationFormPolynomial};
 
     #[test]
     fn test_gkr_sumcheck() {
@@ -193,12 +203,47 @@ mod tests {
             EvaluationFormPolynomial::new(&values2),
             EvaluationFormPolynomial::new(&values3),
         ]);
-        let mut sum_poly = SumPolynomial::new(vec![poly, poly1]);
-    
+        let sum_poly = SumPolynomial::new(vec![poly, poly1]);
+
         let transcript = proof(sum_poly.clone(), Fr::from(6));
         verify(sum_poly, transcript.0, transcript.1);
+    }
+
+    #[test]
+    fn test_gkr_sumcheck_many_factors() {
+        let a = EvaluationFormPolynomial::new(&vec![
+            Fr::from(1),
+            Fr::from(2),
+            Fr::from(3),
+            Fr::from(4),
+        ]);
+        let b = EvaluationFormPolynomial::new(&vec![
+            Fr::from(5),
+            Fr::from(6),
+            Fr::from(7),
+            Fr::from(8),
+        ]);
+        // a * b * a + b, of degree three in each variable
+        let sum_poly = SumPolynomial::new(vec![
+            ProductPolynomial::new(vec![a.clone(), b.clone(), a]),
+            ProductPolynomial::new(vec![b]),
+        ]);
 
-        print_summary!()
+        let (sum, round_polys, _) = proof(sum_poly.clone(), Fr::from(246));
+        assert_eq!(sum, Fr::from(246));
+        assert!(round_polys.iter().all(|round_poly| round_poly.len() == 4));
+        verify(sum_poly, sum, round_polys);
+    }
+
+    #[test]
+    #[should_panic(expected = "claimed sum does not match the polynomial")]
+    fn test_proof_rejects_wrong_claim() {
+        let values: Vec<Fr> = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)];
+        let sum_poly = SumPolynomial::new(vec![ProductPolynomial::new(vec![
+            EvaluationFormPolynomial::new(&values),
+            EvaluationFormPolynomial::new(&values),
+        ])]);
+        proof(sum_poly, Fr::from(31));
     }
 
     // #[test]
@@ -221,4 +266,28 @@ mod tests {
 
     //     verify(values, transcript.0, transcript.1);
     // }
+
+    #[test]
+    fn test_verify_rounds_rejects_wrong_claim() {
+        let values: Vec<Fr> = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)];
+        let poly = SumPolynomial::new(vec![ProductPolynomial::new(vec![
+            EvaluationFormPolynomial::new(&values),
+            EvaluationFormPolynomial::new(&values),
+        ])]);
+
+        let mut prover_transcript = FiatShamir::new(Sha3_256::new());
+        let (round_polys, challenges) = prove_rounds(poly.clone(), &mut prover_transcript);
+
+        let mut verifier_transcript = FiatShamir::new(Sha3_256::new());
+        let (final_claim, verifier_challenges) =
+            verify_rounds(Fr::from(30), &round_polys, 2, 2, &mut verifier_transcript).unwrap();
+        assert_eq!(verifier_challenges, challenges);
+        assert_eq!(poly.polyomials[0].clone().evaluate(challenges), final_claim);
+
+        let mut verifier_transcript = FiatShamir::<Sha3_256, Fr>::new(Sha3_256::new());
+        assert_eq!(
+            verify_rounds(Fr::from(31), &round_polys, 2, 2, &mut verifier_transcript),
+            Err(SumcheckError::RoundSumMismatch { round: 0 })
+        );
+    }
 }
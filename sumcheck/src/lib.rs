@@ -1,11 +1,12 @@
-mod gkr_sumcheck;
-use evaluation_form_poly::EvaluationFormPolynomial;
+pub mod error;
+pub mod gkr_sumcheck;
+use multilinear_polynomial::EvaluationFormPolynomial;
 
 use ark_ff::{BigInteger, PrimeField};
 use fiat_shamir::{self, FiatShamir};
 use sha3::{Digest, Sha3_256};
 
-fn verify<F: PrimeField>(init_polynomial: Vec<F>, mut claimed_sum: F, uni_poly: Vec<Vec<F>>) -> F {
+pub fn verify<F: PrimeField>(init_polynomial: Vec<F>, mut claimed_sum: F, uni_poly: Vec<Vec<F>>) -> F {
     let mut uni_polynomial: EvaluationFormPolynomial<F> =
         EvaluationFormPolynomial::new(&uni_poly[0]);
     assert_eq!(
@@ -34,9 +35,9 @@ fn verify<F: PrimeField>(init_polynomial: Vec<F>, mut claimed_sum: F, uni_poly:
 
     let mut init_poly = EvaluationFormPolynomial::new(&init_polynomial);
 
-    for i in 0..uni_poly.len() {
+    for round_poly in &uni_poly {
         fiat_shamir.absorb(
-            &uni_poly[i]
+            &round_poly
                 .iter()
                 .flat_map(|f| f.into_bigint().to_bits_be().into_iter().map(|b| b as u8))
                 .collect::<Vec<u8>>(),
@@ -44,7 +45,7 @@ fn verify<F: PrimeField>(init_polynomial: Vec<F>, mut claimed_sum: F, uni_poly:
 
         let challenge = fiat_shamir.squeeze();
 
-        uni_polynomial = EvaluationFormPolynomial::new(&uni_poly[i]);
+        uni_polynomial = EvaluationFormPolynomial::new(round_poly);
 
         claimed_sum = uni_polynomial.partial_evaluate(challenge, 0).representation[0];
 
@@ -56,7 +57,7 @@ fn verify<F: PrimeField>(init_polynomial: Vec<F>, mut claimed_sum: F, uni_poly:
     claimed_sum
 }
 
-fn proof<F: PrimeField>(mut init_polynomial: Vec<F>, claimed_sum: F) -> (F, Vec<Vec<F>>) {
+pub fn proof<F: PrimeField>(mut init_polynomial: Vec<F>, claimed_sum: F) -> (F, Vec<Vec<F>>) {
     let mut unipoly_vec = vec![];
     let no_of_variables = init_polynomial.len().ilog2();
 
@@ -85,9 +86,9 @@ fn proof<F: PrimeField>(mut init_polynomial: Vec<F>, claimed_sum: F) -> (F, Vec<
         );
         let challenge = fiat_shamir.squeeze();
 
-        let mut multilinear_poly = EvaluationFormPolynomial::new(&init_polynomial);
+        let multilinear_poly = EvaluationFormPolynomial::new(&init_polynomial);
 
-        let mut uni_polynomial = EvaluationFormPolynomial::new(&uni_polynomial_eval);
+        let uni_polynomial = EvaluationFormPolynomial::new(&uni_polynomial_eval);
 
         let verifier_sum: &F = &uni_polynomial.partial_evaluate(challenge, 0).representation[0];
 
@@ -102,15 +103,13 @@ fn proof<F: PrimeField>(mut init_polynomial: Vec<F>, claimed_sum: F) -> (F, Vec<
     (claimed_sum, unipoly_vec)
 }
 
-fn proof_engine<F: PrimeField>(evaluation_form_vec: &Vec<F>) -> Vec<F> {
+fn proof_engine<F: PrimeField>(evaluation_form_vec: &[F]) -> Vec<F> {
     let mid = evaluation_form_vec.len() / 2;
     let first_half_sum: F = evaluation_form_vec[..mid]
         .iter()
-        .map(|monomial| monomial)
         .sum();
     let second_half_sum: F = evaluation_form_vec[mid..]
         .iter()
-        .map(|monomial| monomial)
         .sum();
     let univariate_polynomial: Vec<F> = vec![first_half_sum, second_half_sum];
     univariate_polynomial
@@ -119,13 +118,11 @@ fn proof_engine<F: PrimeField>(evaluation_form_vec: &Vec<F>) -> Vec<F> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use field_tracker::{Ft, start_tscope, end_tscope, print_summary, summary};
-
-    type Fr = Ft!(ark_bn254::Fq);
+    use ark_bn254::Fq as Fr;
 
     #[test]
     fn test_sumcheck() {
-        let values: Vec<Fr> = vec![Fr::from(2); 1<<20];
+        let values: Vec<Fr> = vec![Fr::from(2); 1 << 15];
         // vec![
         //     Fr::from(0),
         //     Fr::from(0),
@@ -139,6 +136,5 @@ mod tests {
         let transcript = proof(values.clone(), Fr::from(65536));
     
         verify(values, transcript.0, transcript.1);
-        print_summary!()
     }
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+//! Single entry point to the crates of this workspace.
+//!
+//! Every crate is re-exported under a short module name, so downstream code can depend on
+//! `zk` alone and be sure all of them agree on the same polynomial types.
+
+/// Univariate polynomials over prime fields (`zkpolynomial`).
+pub use polynomials as univariate;
+
+/// Multilinear polynomials in evaluation and sparse form.
+pub use multilinear_polynomial as multilinear;
+
+/// Fiat–Shamir transcript.
+pub use fiat_shamir;
+
+/// Sumcheck prover and verifier.
+pub use sumcheck;
+
+/// Multilinear KZG commitments.
+pub use ml_kzg;
+
+/// GKR proofs for layered arithmetic circuits.
+pub use gkr;
+
+/// Shamir secret sharing.
+pub use shamir_secret_sharing_threshhold_scheme as shamir;
+
+pub use multilinear_polynomial::EvaluationFormPolynomial;
+pub use polynomials::UnivariatePolynomial;